@@ -0,0 +1,56 @@
+//! Benchmarks every 2021 day on its real input, measuring parse, part01 and part02 separately.
+//!
+//! Results are kept in `benches/baselines` rather than `target/` so they survive a `cargo clean`,
+//! `.cargo/config.toml` points `CRITERION_HOME` there. Every run is compared with the previous one,
+//! and named baselines hold a reference while working on a change:
+//!
+//! ```text
+//! cargo bench --bench days -- --save-baseline before 2021/05
+//! cargo bench --bench days -- --baseline before 2021/05
+//! ```
+
+use std::hint::black_box;
+
+use adventofcode21::{
+    challenge::Solver,
+    input::Inputs,
+    y2021::{self, *},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_day<S: Solver>(c: &mut Criterion, inputs: &Inputs, day: usize, solver: S) {
+    let raw = inputs
+        .read(y2021::YEAR, day, false)
+        .unwrap_or_else(|e| panic!("reading the input of day {}: {:#}", day, e));
+    let input = solver.parse(&raw).expect("real inputs parse");
+
+    let mut group = c.benchmark_group(format!("{}/{:02}", y2021::YEAR, day));
+    group.bench_function("parse", |b| b.iter(|| solver.parse(black_box(&raw))));
+    group.bench_function("part01", |b| b.iter(|| solver.part01(black_box(&input))));
+    group.bench_function("part02", |b| b.iter(|| solver.part02(black_box(&input))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    let inputs = Inputs::new(None);
+    bench_day(c, &inputs, 1, day01::Day01);
+    bench_day(c, &inputs, 2, day02::Day02);
+    bench_day(c, &inputs, 3, day03::Day03);
+    bench_day(c, &inputs, 4, day04::Day04);
+    bench_day(c, &inputs, 5, day05::Day05);
+    bench_day(c, &inputs, 6, day06::Day06);
+    bench_day(c, &inputs, 7, day07::Day07);
+    bench_day(c, &inputs, 8, day08::Day08);
+    bench_day(c, &inputs, 9, day09::Day09);
+    bench_day(c, &inputs, 10, day10::Day10);
+    bench_day(c, &inputs, 11, day11::Day11);
+    bench_day(c, &inputs, 12, day12::Day12);
+    bench_day(c, &inputs, 13, day13::Day13);
+    bench_day(c, &inputs, 14, day14::Day14);
+    bench_day(c, &inputs, 15, day15::Day15);
+    bench_day(c, &inputs, 16, day16::Day16);
+    bench_day(c, &inputs, 17, day17::Day17);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
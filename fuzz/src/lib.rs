@@ -0,0 +1,9 @@
+use adventofcode21::registry::Registry;
+
+/// Parses `input` as a 2021 puzzle input for `day` and solves both parts. The answer doesn't
+/// matter, malformed input only has to come back as an error rather than a panic or a hang.
+pub fn solve(day: usize, input: &str) {
+    let registry = Registry::new();
+    let solver = registry.get(2021, day).expect("fuzzed days are registered");
+    let _ = solver.solve_timed(input);
+}
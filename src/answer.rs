@@ -0,0 +1,102 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A puzzle answer. Most answers are numbers, some are letters and some are a picture that can
+/// only be read by a human.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Multi-line rendering, e.g. a grid of `#` and `.`
+    Render(String),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Render(_))
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+// integers are numbers, anything else is text
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse() {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(s.to_owned()),
+        })
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Render(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+// numbers are written as numbers so that structured output stays easy to consume, everything else
+// is a string
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_i64(*n),
+            Answer::Text(s) | Answer::Render(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(i64),
+            Text(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Number(n) => Answer::Number(n),
+            Raw::Text(s) if s.contains('\n') => Answer::Render(s),
+            Raw::Text(s) => Answer::Text(s),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let answers = vec![
+            Answer::Number(42),
+            Answer::Text("ABC".to_owned()),
+            Answer::Render("#.\n.#\n".to_owned()),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(r##"[42,"ABC","#.\n.#\n"]"##, json);
+
+        let parsed: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(answers, parsed);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Answer::Number(-12), "-12".parse().unwrap());
+        assert_eq!(
+            Answer::Text("PZFJHRFZ".to_owned()),
+            "PZFJHRFZ".parse().unwrap()
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::challenge::{Challenge, Part, Timings};
+
+/// min/median/max of a set of samples for a single phase.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct BenchReport {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part01: Option<Stats>,
+    pub part02: Option<Stats>,
+    pub total: Stats,
+}
+
+// solves the input `runs` times collecting the timings for each phase, if `part` is given only that
+// part is run
+pub fn bench(
+    solver: &dyn Challenge,
+    input: &str,
+    runs: usize,
+    part: Option<Part>,
+) -> Result<BenchReport> {
+    anyhow::ensure!(runs > 0, "bench requires at least one run");
+
+    let mut samples: Vec<Timings> = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let timings = match part {
+            Some(part) => solver.solve_part(input, part)?.1,
+            None => solver.solve_timed(input)?.1,
+        };
+        samples.push(timings);
+    }
+
+    let phase = |f: fn(&Timings) -> Duration| Stats::from_samples(samples.iter().map(f).collect());
+    Ok(BenchReport {
+        runs,
+        parse: phase(|t| t.parse),
+        part01: Part::One.runs(part).then(|| phase(|t| t.part01)),
+        part02: Part::Two.runs(part).then(|| phase(|t| t.part02)),
+        total: phase(|t| t.total()),
+    })
+}
+
+pub fn print_report(year: usize, day: usize, name: &str, report: &BenchReport) {
+    println!(
+        "--- {} Day {:02}: {} ({} runs) ---",
+        year, day, name, report.runs
+    );
+    println!("  {:<8} {:>12} {:>12} {:>12}", "", "min", "median", "max");
+    for (label, stats) in [
+        ("Parse", Some(report.parse)),
+        ("Part 1", report.part01),
+        ("Part 2", report.part02),
+        ("Total", Some(report.total)),
+    ] {
+        let Some(stats) = stats else {
+            continue;
+        };
+        println!(
+            "  {:<8} {:>12} {:>12} {:>12}",
+            label,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.max),
+        );
+    }
+}
@@ -1,5 +1,159 @@
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+use crate::{
+    answer::Answer,
+    mem::{self, MemReport},
+};
+
+/// A single day's puzzle. `Input` is the parsed puzzle input that is shared by both parts.
 pub trait Solver {
-    fn name(&self) -> String;
-    fn part01(&self, input: &str) -> i64;
-    fn part02(&self, input: &str) -> i64;
+    type Input<'a>;
+
+    fn name(&self) -> &'static str;
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>>;
+    fn part01(&self, input: &Self::Input<'_>) -> Result<Answer>;
+    fn part02(&self, input: &Self::Input<'_>) -> Result<Answer>;
+}
+
+/// Object safe view of a `Solver` that works directly on the raw puzzle input, this is what the
+/// registry hands out.
+pub trait Challenge: Sync {
+    fn name(&self) -> &'static str;
+    fn solve_timed(&self, input: &str) -> Result<(SolveInfo, Timings)>;
+    /// Parses the input and runs only `part`, the timing of the other part is left at zero.
+    fn solve_part(&self, input: &str, part: Part) -> Result<(Answer, Timings)>;
+    /// Parses the input and runs both parts, or only `part`, counting the allocations of each phase
+    /// with [`mem::measure`].
+    fn solve_mem(&self, input: &str, part: Option<Part>) -> Result<MemReport>;
+}
+
+/// Both answers of a solved day.
+pub struct SolveInfo {
+    pub challenge: &'static str,
+    pub part01: Answer,
+    pub part02: Answer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Whether this part runs when only `part` was asked for, every part runs if none was.
+    pub fn runs(self, part: Option<Part>) -> bool {
+        part.is_none() || part == Some(self)
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("unknown part {} (expected 1 or 2)", s)),
+        }
+    }
+}
+
+/// Wall-clock time spent in each phase of a solve.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part01: Duration,
+    pub part02: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part01 + self.part02
+    }
+}
+
+impl<S: Solver + Sync> Challenge for S {
+    fn name(&self) -> &'static str {
+        Solver::name(self)
+    }
+
+    fn solve_part(&self, input: &str, part: Part) -> Result<(Answer, Timings)> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => Solver::part01(self, &input)?,
+            Part::Two => Solver::part02(self, &input)?,
+        };
+        let elapsed = start.elapsed();
+
+        let mut timings = Timings {
+            parse,
+            ..Default::default()
+        };
+        match part {
+            Part::One => timings.part01 = elapsed,
+            Part::Two => timings.part02 = elapsed,
+        }
+        Ok((answer, timings))
+    }
+
+    fn solve_mem(&self, input: &str, part: Option<Part>) -> Result<MemReport> {
+        let (input, parse) = mem::measure(|| self.parse(input));
+        let input = input?;
+
+        let part01 = if Part::One.runs(part) {
+            let (answer, usage) = mem::measure(|| Solver::part01(self, &input));
+            answer?;
+            Some(usage)
+        } else {
+            None
+        };
+        let part02 = if Part::Two.runs(part) {
+            let (answer, usage) = mem::measure(|| Solver::part02(self, &input));
+            answer?;
+            Some(usage)
+        } else {
+            None
+        };
+        Ok(MemReport {
+            parse,
+            part01,
+            part02,
+        })
+    }
+
+    fn solve_timed(&self, input: &str) -> Result<(SolveInfo, Timings)> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let part01 = Solver::part01(self, &input)?;
+        let part01_time = start.elapsed();
+
+        let start = Instant::now();
+        let part02 = Solver::part02(self, &input)?;
+        let part02_time = start.elapsed();
+
+        let solve = SolveInfo {
+            challenge: Solver::name(self),
+            part01,
+            part02,
+        };
+        let timings = Timings {
+            parse,
+            part01: part01_time,
+            part02: part02_time,
+        };
+        Ok((solve, timings))
+    }
 }
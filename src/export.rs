@@ -0,0 +1,350 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    grid::Grid,
+    visualize::{Cell, Color, Frame, FrameSink},
+};
+
+/// File format of exported frames. Stills are written one file per frame, a gif holds every
+/// frame as an animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+            ImageFormat::Gif => "gif",
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "png" => Ok(ImageFormat::Png),
+            "gif" => Ok(ImageFormat::Gif),
+            _ => Err(format!(
+                "unknown image format {} (expected ppm, png or gif)",
+                s
+            )),
+        }
+    }
+}
+
+// largest image drawn, three bytes a pixel keep a still at 192 MiB of rgb
+const MAX_PIXELS: usize = 1 << 26;
+
+const BACKGROUND: u8 = 0;
+const FOREGROUND: u8 = 1;
+// digits 0 to 9 follow the foreground as a dark to light ramp, then the colors
+const DIGITS: u8 = 2;
+const COLORS: u8 = DIGITS + 10;
+
+/// The colors every exported image is drawn with, as `[r, g, b, ...]`. Cells are mapped to an
+/// index into it by [`palette_index`].
+pub fn palette() -> Vec<u8> {
+    let mut palette = vec![0x10, 0x10, 0x18, 0xf0, 0xf0, 0xf0];
+    for digit in 0..10u8 {
+        let gray = 0x30 + digit * 0x14;
+        palette.extend([gray, gray, gray]);
+    }
+    for color in [Color::Red, Color::Green, Color::Yellow, Color::Blue] {
+        palette.extend(rgb(color));
+    }
+    palette
+}
+
+fn rgb(color: Color) -> [u8; 3] {
+    match color {
+        Color::Red => [0xe0, 0x30, 0x30],
+        Color::Green => [0x30, 0xc0, 0x40],
+        Color::Yellow => [0xf0, 0xd0, 0x30],
+        Color::Blue => [0x40, 0x70, 0xf0],
+    }
+}
+
+/// Colored cells keep their color, digits are shades of gray, blanks and `.` are background and
+/// anything else is drawn in the foreground color.
+pub fn palette_index(cell: &Cell) -> u8 {
+    match (cell.color, cell.ch) {
+        (Some(color), _) => COLORS + color as u8,
+        (None, ' ' | '.') => BACKGROUND,
+        (None, ch) => ch.to_digit(10).map_or(FOREGROUND, |d| DIGITS + d as u8),
+    }
+}
+
+/// Palette indices of `cells` drawn `scale` pixels per cell on a `width` by `height` pixel
+/// canvas, cells that don't fit are cut off and the rest is background.
+fn pixels(cells: &Grid<Cell>, scale: usize, width: usize, height: usize) -> Vec<u8> {
+    let mut pixels = vec![BACKGROUND; width * height];
+    for ((x, y), cell) in cells.iter() {
+        let index = palette_index(cell);
+        for py in y * scale..((y + 1) * scale).min(height) {
+            let row = py * width;
+            for px in x * scale..((x + 1) * scale).min(width) {
+                pixels[row + px] = index;
+            }
+        }
+    }
+    pixels
+}
+
+/// Writes the frames of a visualization to image files in a directory, `{prefix}-NNNN.ppm` or
+/// `.png` for each frame or a single `{prefix}.gif`.
+pub struct Exporter {
+    dir: PathBuf,
+    prefix: String,
+    format: ImageFormat,
+    scale: usize,
+    /// Gif frame delay in hundredths of a second.
+    delay: u16,
+    frames: usize,
+    written: Vec<PathBuf>,
+    gif: Option<gif::Encoder<BufWriter<File>>>,
+    // size of the gif, set by its first frame
+    canvas: (usize, usize),
+}
+
+impl Exporter {
+    /// `scale` is the number of pixels per cell, `fps` the playback speed of a gif.
+    pub fn new(
+        dir: impl Into<PathBuf>,
+        prefix: impl Into<String>,
+        format: ImageFormat,
+        scale: usize,
+        fps: f64,
+    ) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).with_context(|| format!("Creating {}", dir.display()))?;
+        let fps = if fps > 0.0 { fps } else { 10.0 };
+        Ok(Self {
+            dir,
+            prefix: prefix.into(),
+            format,
+            scale: scale.max(1),
+            delay: (100.0 / fps).round().clamp(1.0, u16::MAX as f64) as u16,
+            frames: 0,
+            written: Vec::new(),
+            gif: None,
+            canvas: (0, 0),
+        })
+    }
+
+    /// Finishes the export, returning the files that were written.
+    pub fn finish(mut self) -> Result<Vec<PathBuf>> {
+        if let Some(gif) = self.gif.take() {
+            gif.into_inner()?.flush()?;
+        }
+        Ok(self.written)
+    }
+
+    fn path(&self) -> PathBuf {
+        let name = match self.format {
+            ImageFormat::Gif => format!("{}.gif", self.prefix),
+            format => format!("{}-{:04}.{}", self.prefix, self.frames, format.extension()),
+        };
+        self.dir.join(name)
+    }
+
+    // pixel size of `cells` at the export scale
+    fn pixel_size(&self, cells: &Grid<Cell>) -> Result<(usize, usize)> {
+        let width = cells.width().checked_mul(self.scale);
+        let height = cells.height().checked_mul(self.scale);
+        match (width, height) {
+            (Some(width), Some(height))
+                if width.checked_mul(height).is_some_and(|n| n <= MAX_PIXELS) =>
+            {
+                Ok((width, height))
+            }
+            _ => anyhow::bail!(
+                "{}x{} cells at {} pixels per cell are too large to export",
+                cells.width(),
+                cells.height(),
+                self.scale
+            ),
+        }
+    }
+
+    fn write_still(&mut self, frame: &Frame) -> Result<()> {
+        let (width, height) = self.pixel_size(&frame.cells)?;
+        let palette = palette();
+        let rgb: Vec<u8> = pixels(&frame.cells, self.scale, width, height)
+            .into_iter()
+            .flat_map(|i| {
+                let i = i as usize * 3;
+                [palette[i], palette[i + 1], palette[i + 2]]
+            })
+            .collect();
+
+        let path = self.path();
+        let mut file = create(&path)?;
+        match self.format {
+            ImageFormat::Ppm => write_ppm(&mut file, width, height, &rgb)?,
+            _ => write_png(&mut file, width, height, &rgb)?,
+        }
+        file.flush()?;
+        self.written.push(path);
+        Ok(())
+    }
+
+    fn write_gif_frame(&mut self, frame: &Frame) -> Result<()> {
+        if self.gif.is_none() {
+            self.canvas = self.pixel_size(&frame.cells)?;
+            let (width, height) = gif_size(self.canvas)?;
+            let path = self.path();
+            let mut encoder = gif::Encoder::new(create(&path)?, width, height, &palette())?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.gif = Some(encoder);
+            self.written.push(path);
+        }
+
+        let (width, height) = self.canvas;
+        let (gif_width, gif_height) = gif_size(self.canvas)?;
+        let mut image = gif::Frame::from_indexed_pixels(
+            gif_width,
+            gif_height,
+            pixels(&frame.cells, self.scale, width, height),
+            None,
+        );
+        image.delay = self.delay;
+        self.gif.as_mut().unwrap().write_frame(&image)?;
+        Ok(())
+    }
+}
+
+impl FrameSink for Exporter {
+    fn frame(&mut self, frame: Frame) -> Result<()> {
+        match self.format {
+            ImageFormat::Gif => self.write_gif_frame(&frame)?,
+            _ => self.write_still(&frame)?,
+        }
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>> {
+    let file = File::create(path).with_context(|| format!("Creating {}", path.display()))?;
+    Ok(BufWriter::new(file))
+}
+
+fn gif_size((width, height): (usize, usize)) -> Result<(u16, u16)> {
+    match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => anyhow::bail!("{}x{} is too large for a gif", width, height),
+    }
+}
+
+/// Writes a binary (P6) PPM image from `[r, g, b, ...]` pixels.
+pub fn write_ppm(w: &mut impl Write, width: usize, height: usize, rgb: &[u8]) -> Result<()> {
+    write!(w, "P6\n{} {}\n255\n", width, height)?;
+    w.write_all(rgb)?;
+    Ok(())
+}
+
+/// Writes an 8 bit RGB PNG image from `[r, g, b, ...]` pixels.
+pub fn write_png(w: &mut impl Write, width: usize, height: usize, rgb: &[u8]) -> Result<()> {
+    let mut encoder = png::Encoder::new(w, width.try_into()?, height.try_into()?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgb)?;
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let cells = Grid::from_rows(vec![
+            vec![Cell::new('.'), Cell::new('#')],
+            vec![Cell::new('9'), Cell::colored('1', Color::Red, '*')],
+        ])
+        .unwrap();
+        Frame::new("test", cells)
+    }
+
+    #[test]
+    fn test_pixels() {
+        let frame = frame();
+        assert_eq!(
+            vec![
+                BACKGROUND,
+                BACKGROUND,
+                FOREGROUND,
+                FOREGROUND,
+                BACKGROUND,
+                BACKGROUND,
+                FOREGROUND,
+                FOREGROUND,
+                DIGITS + 9,
+                DIGITS + 9,
+                COLORS,
+                COLORS,
+            ],
+            pixels(&frame.cells, 2, 4, 3)
+        );
+        assert_eq!(COLORS as usize + 4, palette().len() / 3);
+    }
+
+    #[test]
+    fn test_export() {
+        let dir = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+        for format in [ImageFormat::Ppm, ImageFormat::Png, ImageFormat::Gif] {
+            let mut exporter = Exporter::new(&dir, "2021-05", format, 3, 10.0).unwrap();
+            exporter.frame(frame()).unwrap();
+            exporter.frame(frame()).unwrap();
+            let written = exporter.finish().unwrap();
+
+            let bytes = fs::read(&written[0]).unwrap();
+            match format {
+                ImageFormat::Ppm => {
+                    assert_eq!(2, written.len());
+                    assert!(bytes.starts_with(b"P6\n6 6\n255\n"));
+                    assert_eq!(11 + 6 * 6 * 3, bytes.len());
+                }
+                ImageFormat::Png => {
+                    assert_eq!(dir.join("2021-05-0001.png"), written[1]);
+                    assert!(bytes.starts_with(b"\x89PNG"));
+                }
+                ImageFormat::Gif => {
+                    assert_eq!(vec![dir.join("2021-05.gif")], written);
+                    assert!(bytes.starts_with(b"GIF89a"));
+                    assert_eq!(Some(&0x3b), bytes.last());
+                }
+            }
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_export_too_large() {
+        let dir = std::env::temp_dir().join(format!("aoc-export-large-{}", std::process::id()));
+        for format in [ImageFormat::Ppm, ImageFormat::Png, ImageFormat::Gif] {
+            for scale in [5000, usize::MAX] {
+                let mut exporter = Exporter::new(&dir, "2021-05", format, scale, 10.0).unwrap();
+                assert!(exporter.frame(frame()).is_err());
+                assert!(exporter.finish().unwrap().is_empty());
+            }
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
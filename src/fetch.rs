@@ -0,0 +1,256 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable holding the session cookie, takes priority over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/scnewma/adventofcode21 input fetcher";
+
+/// A response from an [`HttpBackend`], only what the fetcher needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Performs HTTP GET requests for the fetcher. Implement this to swap out the HTTP client, e.g.
+/// to serve canned responses.
+pub trait HttpBackend: Send + Sync {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// The default backend using a blocking `ureq` agent.
+pub struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+impl UreqBackend {
+    pub fn new() -> Self {
+        let config = ureq::Agent::config_builder()
+            // non-200 responses are reported by the fetcher along with their body
+            .http_status_as_error(false)
+            .build();
+        Self {
+            agent: config.into(),
+        }
+    }
+}
+
+impl Default for UreqBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+        let mut request = self.agent.get(url);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let mut response = request.call().with_context(|| format!("GET {}", url))?;
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .with_context(|| format!("Reading response body of {}", url))?;
+        Ok(Response { status, body })
+    }
+}
+
+/// Written next to each fetched input as `NN.meta.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub url: String,
+    pub status: u16,
+    pub bytes: usize,
+    /// Seconds since the unix epoch.
+    pub fetched_at: u64,
+}
+
+/// Downloads puzzle inputs from `{base_url}/{year}/day/{day}/input` using the session cookie of a
+/// logged in user.
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    backend: Box<dyn HttpBackend>,
+}
+
+impl Fetcher {
+    pub fn new(
+        base_url: impl Into<String>,
+        session: Option<String>,
+        backend: impl HttpBackend + 'static,
+    ) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session,
+            backend: Box::new(backend),
+        }
+    }
+
+    pub fn url(&self, year: usize, day: usize) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Fetches the input for `day`. Only successful responses are returned, anything else is
+    /// reported as an error with the start of the response body which usually says what is wrong.
+    pub fn fetch(&self, year: usize, day: usize) -> Result<(String, Metadata)> {
+        let session = self.session.as_deref().with_context(|| {
+            format!(
+                "No session token to fetch {} day {} with, set {} or use a session file",
+                year, day, SESSION_ENV
+            )
+        })?;
+
+        let url = self.url(year, day);
+        let cookie = format!("session={}", session);
+        let response = self
+            .backend
+            .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+        if response.status != 200 {
+            anyhow::bail!(
+                "GET {} returned {}: {}",
+                url,
+                response.status,
+                response.body.lines().next().unwrap_or("").trim()
+            );
+        }
+
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let metadata = Metadata {
+            url,
+            status: response.status,
+            bytes: response.body.len(),
+            fetched_at,
+        };
+        Ok((response.body, metadata))
+    }
+}
+
+/// Reads the session token from `$AOC_SESSION`, falling back to the first line of `file`. Returns
+/// `None` if neither is set.
+pub fn session_token(file: &Path) -> Result<Option<String>> {
+    if let Ok(token) = std::env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(Some(token.trim().to_owned()));
+        }
+    }
+
+    match fs::read_to_string(file) {
+        Ok(s) => Ok(s.lines().next().map(|l| l.trim().to_owned())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Reading session file {}", file.display())),
+    }
+}
+
+/// Path of the metadata written alongside a cached input.
+pub fn metadata_path(input: &Path) -> PathBuf {
+    input.with_extension("meta.json")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::*;
+
+    /// A minimal HTTP server on localhost that answers every request with `status` and `body`.
+    /// Returns its base URL and the request lines plus headers it has received.
+    pub(crate) fn mock_server(
+        status: u16,
+        body: &'static str,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(&stream);
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                received.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (base_url, requests) = mock_server(200, "1\n2\n3\n");
+        let fetcher = Fetcher::new(
+            base_url.clone() + "/",
+            Some("abc".to_owned()),
+            UreqBackend::new(),
+        );
+
+        let (input, metadata) = fetcher.fetch(2021, 7).unwrap();
+        assert_eq!("1\n2\n3\n", input);
+        assert_eq!(format!("{}/2021/day/7/input", base_url), metadata.url);
+        assert_eq!(6, metadata.bytes);
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /2021/day/7/input HTTP/1.1\r\n"));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains("cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let (base_url, _) = mock_server(400, "Puzzle inputs differ by user.  Please log in.\n");
+        let fetcher = Fetcher::new(base_url, Some("abc".to_owned()), UreqBackend::new());
+        let err = fetcher.fetch(2021, 7).unwrap_err().to_string();
+        assert!(err.ends_with("returned 400: Puzzle inputs differ by user.  Please log in."));
+
+        let fetcher = Fetcher::new("http://unused", None, UreqBackend::new());
+        assert!(fetcher.fetch(2021, 7).is_err());
+    }
+
+    #[test]
+    fn test_session_file() {
+        let file = std::env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        fs::write(&file, "token\n").unwrap();
+        // the environment variable can't be cleared safely while other tests run, only check the
+        // file when it is not set
+        if std::env::var(SESSION_ENV).is_err() {
+            assert_eq!(Some("token".to_owned()), session_token(&file).unwrap());
+        }
+        fs::remove_file(&file).unwrap();
+        if std::env::var(SESSION_ENV).is_err() {
+            assert_eq!(None, session_token(&file).unwrap());
+        }
+    }
+}
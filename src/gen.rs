@@ -0,0 +1,141 @@
+use std::fmt::Write;
+
+/// A small seeded random number generator (SplitMix64). Generated inputs only depend on the seed
+/// so that a stress test can be reproduced, which is why this doesn't use an external crate whose
+/// output could change between versions.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        // widening multiply instead of a modulo to avoid its bias
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in the inclusive range `lo..=hi`.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo) as u64 + 1) as i64
+    }
+
+    /// A number in `0..n` as an index, `n` must not be 0.
+    pub fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+
+    /// True with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// A day that can generate synthetic puzzle inputs in its exact input format, e.g. to stress
+/// test it with inputs much larger than the real one.
+pub trait Generate: Sync {
+    /// What `size` counts, e.g. "readings".
+    fn size_of(&self) -> &'static str;
+
+    /// The size of a typical puzzle input.
+    fn default_size(&self) -> usize;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+}
+
+/// A grid of `width` by `height` digits picked from `lo..=hi`, one row per line.
+pub fn digit_grid(rng: &mut Rng, width: usize, height: usize, lo: u32, hi: u32) -> String {
+    let mut out = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        for _ in 0..width {
+            let digit = rng.range(lo as i64, hi as i64) as u32;
+            out.push(char::from_digit(digit, 10).unwrap());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Joins `items` with `delim` and ends the result with a newline.
+pub fn join_line<T: std::fmt::Display>(items: impl IntoIterator<Item = T>, delim: &str) -> String {
+    let mut out = String::new();
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(delim);
+        }
+        write!(out, "{}", item).unwrap();
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Registry;
+
+    #[test]
+    fn test_rng() {
+        let sample = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(-3, 3)).collect::<Vec<_>>()
+        };
+        assert_eq!(sample(7), sample(7));
+        assert_ne!(sample(7), sample(8));
+        assert!(sample(7).iter().all(|n| (-3..=3).contains(n)));
+        assert!((-3..=3).all(|n| sample(7).contains(&n)));
+
+        let mut items: Vec<_> = (0..10).collect();
+        Rng::new(1).shuffle(&mut items);
+        assert_ne!((0..10).collect::<Vec<_>>(), items);
+        items.sort_unstable();
+        assert_eq!((0..10).collect::<Vec<_>>(), items);
+    }
+
+    // every generated input must be solvable, at the default size and a small one
+    #[test]
+    fn test_generators() {
+        let registry = Registry::new();
+        for (year, day, generator) in registry.generators() {
+            let solver = registry.get(year, day).unwrap();
+            for seed in 0..3 {
+                for size in [1, generator.default_size().min(20)] {
+                    let input = generator.generate(&mut Rng::new(seed), size);
+                    if let Err(e) = solver.solve_timed(&input) {
+                        panic!(
+                            "{} day {} seed {} size {}: {:#}\n{}",
+                            year, day, seed, size, e, input
+                        );
+                    }
+                }
+            }
+            let input = generator.generate(&mut Rng::new(0), generator.default_size());
+            assert_eq!(
+                input,
+                generator.generate(&mut Rng::new(0), generator.default_size())
+            );
+            assert!(solver.solve_timed(&input).is_ok(), "{} day {}", year, day);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A position within a [`Grid`] as `(x, y)`, `(0, 0)` is the top left.
+pub type Point = (usize, usize);
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Creates a grid from its rows. Returns `None` if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[self.offset(point)])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if !self.contains(point) {
+            return None;
+        }
+        let offset = self.offset(point);
+        Some(&mut self.cells[offset])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every point of the grid along with its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The rows of the grid, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero size, a grid without columns has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Applies `f` to every cell, keeping the shape of the grid.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The up to 4 points directly above, below, left and right of `point` that are within the
+    /// grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(point, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// The up to 8 points around `point`, diagonals included, that are within the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(
+            point,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbors(
+        &self,
+        (x, y): Point,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + '_ {
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let point = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(point).then_some(point)
+        })
+    }
+
+    fn offset(&self, (x, y): Point) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(value) => value,
+            None => panic!(
+                "point {:?} out of bounds of {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(value) => value,
+            None => panic!(
+                "point {:?} out of bounds of {}x{} grid",
+                point, width, height
+            ),
+        }
+    }
+}
+
+/// Writes one line per row with the cells next to each other, meant for grids of digits or
+/// characters.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = grid();
+        assert_eq!((3, 2, 6), (grid.width(), grid.height(), grid.len()));
+        assert_eq!(Some(&6), grid.get((2, 1)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(None, grid.get((0, 2)));
+
+        grid[(1, 0)] = 7;
+        assert_eq!(7, grid[(1, 0)]);
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)],
+            grid.points().collect::<Vec<_>>()
+        );
+        assert_eq!(None, Grid::from_rows(vec![vec![1, 2], vec![3]]));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbors4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (0, 1), (2, 1)],
+            grid.neighbors4((1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)],
+            grid.neighbors8((1, 1)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("123\n456\n", grid().to_string());
+        assert_eq!("246\n81012\n", grid().map(|n| n * 2).to_string());
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::fetch::{self, Fetcher};
+
+/// Locates puzzle inputs named `YYYY/NN.txt` and `YYYY/NN.test.txt` within an inputs directory. The
+/// directory doubles as a cache for a [`Fetcher`], real inputs that are missing are downloaded and
+/// stored there.
+pub struct Inputs {
+    dir: PathBuf,
+    fetcher: Option<Fetcher>,
+}
+
+impl Inputs {
+    /// Uses `dir` if given, otherwise `inputs` in the current directory and finally the `inputs`
+    /// directory of this crate, see [`locate`].
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        let dir = dir.unwrap_or_else(|| locate("inputs"));
+        Self { dir, fetcher: None }
+    }
+
+    /// Downloads missing real inputs with `fetcher`.
+    pub fn with_fetcher(mut self, fetcher: Fetcher) -> Self {
+        self.fetcher = Some(fetcher);
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The directory holding the inputs of `year`.
+    pub fn year_dir(&self, year: usize) -> PathBuf {
+        self.dir.join(year.to_string())
+    }
+
+    pub fn path(&self, year: usize, day: usize, test_input: bool) -> PathBuf {
+        if test_input {
+            self.year_dir(year).join(format!("{:0>2}.test.txt", day))
+        } else {
+            self.year_dir(year).join(format!("{:0>2}.txt", day))
+        }
+    }
+
+    /// Reads the input from the inputs directory, fetching and caching it first if it is a real
+    /// input that has not been downloaded yet. Test inputs are never fetched. An empty real input,
+    /// like the one `new-day` leaves behind, counts as not downloaded.
+    pub fn read(&self, year: usize, day: usize, test_input: bool) -> Result<String> {
+        let path = self.path(year, day, test_input);
+        let missing = fs::metadata(&path).map_or(true, |meta| meta.len() == 0);
+        match &self.fetcher {
+            Some(fetcher) if !test_input && missing => self.fetch(fetcher, year, day, &path),
+            _ => read_input(&path),
+        }
+    }
+
+    fn fetch(&self, fetcher: &Fetcher, year: usize, day: usize, path: &Path) -> Result<String> {
+        let (input, metadata) = fetcher
+            .fetch(year, day)
+            .with_context(|| format!("{} not found, fetching it", path.display()))?;
+
+        let dir = self.year_dir(year);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Creating directory {}", dir.display()))?;
+        let meta_path = fetch::metadata_path(path);
+        fs::write(&meta_path, serde_json::to_string_pretty(&metadata)? + "\n")
+            .with_context(|| format!("Writing file {}", meta_path.display()))?;
+        // written last so that an input is only ever cached along with its metadata
+        fs::write(path, &input).with_context(|| format!("Writing file {}", path.display()))?;
+        Ok(input)
+    }
+}
+
+/// Resolves a relative `path` that doesn't exist in the current directory against this crate's
+/// directory instead, so that the binary finds the inputs and answers of the repo when run outside
+/// of the repo root.
+pub fn locate(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    if path.is_absolute() || path.exists() {
+        path.to_owned()
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+    }
+}
+
+/// Reads an input file, `-` reads from stdin.
+pub fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Reading stdin")?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).with_context(|| format!("Reading file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let inputs = Inputs::new(Some(PathBuf::from("elsewhere")));
+        assert_eq!(
+            Path::new("elsewhere/2021/05.txt"),
+            inputs.path(2021, 5, false)
+        );
+        assert_eq!(
+            Path::new("elsewhere/2015/12.test.txt"),
+            inputs.path(2015, 12, true)
+        );
+    }
+
+    #[test]
+    fn test_locate() {
+        assert_eq!(Path::new("src/input.rs"), locate("src/input.rs"));
+        assert_eq!(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("missing.toml"),
+            locate("missing.toml")
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let inputs = Inputs::new(None);
+        assert!(inputs.read(2021, 1, true).unwrap().starts_with("199\n"));
+    }
+
+    #[test]
+    fn test_read_fetches_missing() {
+        let (base_url, requests) = fetch::tests::mock_server(200, "1\n2\n");
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let fetcher = Fetcher::new(base_url, Some("abc".to_owned()), fetch::UreqBackend::new());
+        let inputs = Inputs::new(Some(dir.clone())).with_fetcher(fetcher);
+
+        assert_eq!("1\n2\n", inputs.read(2021, 3, false).unwrap());
+        // served from the cache the second time
+        assert_eq!("1\n2\n", inputs.read(2021, 3, false).unwrap());
+        assert_eq!(1, requests.lock().unwrap().len());
+
+        let meta = fs::read_to_string(dir.join("2021/03.meta.json")).unwrap();
+        let meta: fetch::Metadata = serde_json::from_str(&meta).unwrap();
+        assert_eq!(4, meta.bytes);
+
+        // test inputs come from the puzzle description and can't be fetched
+        assert!(inputs.read(2021, 3, true).is_err());
+        assert_eq!(1, requests.lock().unwrap().len());
+
+        // the empty input scaffolded by new-day is fetched over
+        fs::write(dir.join("2021/04.txt"), "").unwrap();
+        assert_eq!("1\n2\n", inputs.read(2021, 4, false).unwrap());
+        assert_eq!(2, requests.lock().unwrap().len());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,35 @@
+//! Advent of Code solutions.
+//!
+//! Every year has a `yYYYY` module with a module per day which exposes its parsed input types
+//! along with `parse_input`, `part01` and `part02`. The `DayNN` types implement
+//! [`challenge::Solver`] and are collected in a [`registry::Registry`] which is what the
+//! `adventofcode` binary runs.
+//!
+//! ```
+//! use adventofcode21::y2021::day16;
+//!
+//! let packet = day16::parse_input("C200B40A82").unwrap();
+//! assert_eq!(3, day16::part02(&packet).unwrap());
+//! ```
+
+pub mod answer;
+pub mod bench;
+pub mod challenge;
+pub mod export;
+pub mod fetch;
+pub mod gen;
+pub mod grid;
+pub mod input;
+pub mod mem;
+pub mod ocr;
+pub mod output;
+pub mod parse;
+pub mod pool;
+pub mod registry;
+pub mod scaffold;
+pub mod search;
+pub mod submissions;
+pub mod verify;
+pub mod visualize;
+pub mod watch;
+pub mod y2021;
@@ -1,97 +1,569 @@
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
 use anyhow::{Context, Result};
 use structopt::StructOpt;
 
-mod challenge;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
+use adventofcode21::{
+    bench,
+    challenge::{Challenge, Part},
+    export::{Exporter, ImageFormat},
+    fetch::{self, Fetcher, UreqBackend},
+    gen::Rng,
+    input::{self, Inputs},
+    mem,
+    output::{self, Format, Record},
+    pool,
+    registry::Registry,
+    scaffold::Scaffold,
+    submissions::{Feedback, Submission, Submissions},
+    verify::{Answers, Verdict},
+    visualize::{self, Terminal},
+    watch::{self, Watcher},
+};
+
+#[global_allocator]
+static ALLOCATOR: mem::Counting = mem::Counting;
 
 #[derive(Debug, StructOpt)]
-#[structopt(name = "adventofcode", about = "Advent of Code solutions: 2021")]
+#[structopt(name = "adventofcode", about = "Advent of Code solutions")]
 struct Opt {
+    #[structopt(subcommand)]
+    command: Option<Command>,
+
+    #[structopt(
+        name = "YEAR",
+        help = "Year to solve, defaults to every year. A lone number up to 25 is the DAY of the latest year."
+    )]
+    year: Option<usize>,
+
     #[structopt(name = "DAY")]
     day: Option<usize>,
 
-    #[structopt(about = "Use test input instead of full input.", short, long)]
+    #[structopt(help = "Use test input instead of full input.", short, long)]
     test: bool,
+
+    #[structopt(help = "Only solve this part (1 or 2).", short, long)]
+    part: Option<Part>,
+
+    #[structopt(
+        help = "Read the puzzle input from this file instead, `-` reads from stdin.",
+        short,
+        long,
+        parse(from_os_str)
+    )]
+    input: Option<PathBuf>,
+
+    #[structopt(
+        help = "Directory containing YYYY/NN.txt and YYYY/NN.test.txt puzzle inputs.",
+        long,
+        parse(from_os_str)
+    )]
+    inputs_dir: Option<PathBuf>,
+
+    #[structopt(
+        help = "Server to fetch missing inputs from.",
+        long,
+        default_value = fetch::DEFAULT_BASE_URL
+    )]
+    base_url: String,
+
+    #[structopt(
+        help = "File containing the session token used to fetch inputs, AOC_SESSION takes priority.",
+        long,
+        default_value = ".aoc-session",
+        parse(from_os_str)
+    )]
+    session_file: PathBuf,
+
+    #[structopt(
+        help = "Solve each day N times and report min/median/max timings.",
+        long,
+        name = "N"
+    )]
+    bench: Option<usize>,
+
+    #[structopt(
+        help = "Report allocations, bytes allocated and peak live bytes of each day's parse and parts.",
+        long
+    )]
+    mem: bool,
+
+    #[structopt(
+        help = "Output format for solve results: text, json or csv.",
+        long,
+        default_value = "text"
+    )]
+    format: Format,
+
+    #[structopt(
+        help = "Check real and test answers against the recorded answers file.",
+        long
+    )]
+    verify: bool,
+
+    #[structopt(
+        help = "Recorded answers file used by --verify, looked for in the repo if not found here.",
+        long,
+        default_value = "answers.toml"
+    )]
+    answers: String,
+
+    #[structopt(
+        help = "Log of submitted answers, computed answers known to be wrong are warned about. Looked for in the repo if not found here.",
+        long,
+        default_value = "submissions.toml"
+    )]
+    submissions: String,
+
+    #[structopt(
+        help = "Re-solve DAY whenever its inputs change or the binary is rebuilt.",
+        short,
+        long
+    )]
+    watch: bool,
+
+    #[structopt(
+        help = "Play DAY's visualization in the terminal instead of printing the answers.",
+        long
+    )]
+    visualize: bool,
+
+    #[structopt(
+        help = "Frames per second of --visualize, 0 steps through the frames with enter.",
+        long,
+        default_value = "10"
+    )]
+    speed: f64,
+
+    #[structopt(
+        help = "Draw --visualize without colors, also turned off by setting NO_COLOR.",
+        long
+    )]
+    no_color: bool,
+
+    #[structopt(
+        help = "Write DAY's visualization as images to this directory instead of playing it.",
+        long,
+        parse(from_os_str)
+    )]
+    export: Option<PathBuf>,
+
+    #[structopt(
+        help = "Image format of --export: ppm or png stills of every frame, or an animated gif.",
+        long,
+        default_value = "png"
+    )]
+    image_format: ImageFormat,
+
+    #[structopt(
+        help = "Pixels per grid cell in --export images.",
+        long,
+        default_value = "4"
+    )]
+    scale: usize,
+
+    #[structopt(
+        help = "Number of days to solve concurrently, defaults to the number of cores.",
+        short,
+        long
+    )]
+    jobs: Option<usize>,
 }
 
-struct SolveInfo {
-    challenge: &'static str,
-    part01: i64,
-    part02: i64,
+/// A solved day: its year, day number and solver.
+type Puzzle<'a> = (usize, usize, &'a dyn Challenge);
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    #[structopt(about = "Generate the module, inputs and registration for a new day.")]
+    NewDay {
+        #[structopt(name = "DAY")]
+        day: usize,
+
+        #[structopt(help = "Puzzle title, e.g. \"Sonar Sweep\".", long)]
+        title: String,
+
+        #[structopt(help = "Year of the puzzle, defaults to the latest year.", long)]
+        year: Option<usize>,
+    },
+    #[structopt(about = "Record an answer that was submitted and the feedback it got.")]
+    Submission {
+        #[structopt(name = "DAY")]
+        day: usize,
+        #[structopt(name = "PART")]
+        part: Part,
+        #[structopt(name = "ANSWER")]
+        answer: String,
+        #[structopt(name = "FEEDBACK", help = "correct, wrong, too-high or too-low")]
+        feedback: Feedback,
+
+        #[structopt(help = "Year of the puzzle, defaults to the latest year.", long)]
+        year: Option<usize>,
+    },
+    #[structopt(about = "Print a generated puzzle input, e.g. to stress test a day.")]
+    Gen {
+        #[structopt(name = "DAY")]
+        day: usize,
+
+        #[structopt(
+            help = "Seed of the generator, the same seed always gives the same input.",
+            long,
+            default_value = "0"
+        )]
+        seed: u64,
+
+        #[structopt(
+            help = "Size of the input, what it counts depends on the day. Defaults to the size of a typical input.",
+            long
+        )]
+        size: Option<usize>,
+
+        #[structopt(help = "Year of the puzzle, defaults to the latest year.", long)]
+        year: Option<usize>,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
     let opt = Opt::from_args();
 
-    let days = [
-        day01::run,
-        day02::run,
-        day03::run,
-        day04::run,
-        day05::run,
-        day06::run,
-        day07::run,
-        day08::run,
-        day09::run,
-        day10::run,
-        day11::run,
-        day12::run,
-        day13::run,
-        day14::run,
-        day15::run,
-        day16::run,
-        day17::run,
-    ];
-
-    if let Some(day) = opt.day {
-        if day > days.len() {
-            anyhow::bail!("Day {} not yet solved!", day)
+    let registry = Registry::new();
+    let session = fetch::session_token(&opt.session_file)?;
+    let fetcher = Fetcher::new(&opt.base_url, session, UreqBackend::new());
+    let inputs = Inputs::new(opt.inputs_dir.clone()).with_fetcher(fetcher);
+
+    let latest_year = registry.latest_year().context("No days solved yet!")?;
+
+    match &opt.command {
+        Some(Command::NewDay { day, title, year }) => {
+            let year = year.unwrap_or(latest_year);
+            let scaffold = Scaffold::new(env!("CARGO_MANIFEST_DIR"));
+            for path in scaffold.new_day(year, *day, title, &inputs)? {
+                println!("wrote {}", path.display());
+            }
+            println!(
+                "record the answers for {} day {} in {} once solved",
+                year,
+                day,
+                input::locate(&opt.answers).display()
+            );
+            return Ok(());
+        }
+        Some(Command::Submission {
+            day,
+            part,
+            answer,
+            feedback,
+            year,
+        }) => {
+            let path = input::locate(&opt.submissions);
+            let mut submissions = Submissions::load(&path)?;
+            let answer = answer.parse()?;
+            submissions.record(
+                year.unwrap_or(latest_year),
+                *day,
+                *part,
+                Submission {
+                    answer,
+                    feedback: *feedback,
+                },
+            );
+            return submissions.save(&path);
+        }
+        Some(Command::Gen {
+            day,
+            seed,
+            size,
+            year,
+        }) => {
+            let year = year.unwrap_or(latest_year);
+            let generator = registry
+                .generator(year, *day)
+                .with_context(|| format!("{} day {} has no generator", year, *day))?;
+            let size = size.unwrap_or_else(|| generator.default_size());
+            let input = generator.generate(&mut Rng::new(*seed), size);
+            io::stdout().lock().write_all(input.as_bytes())?;
+            eprintln!(
+                "generated {} day {} with {} {} (seed {})",
+                year,
+                day,
+                size,
+                generator.size_of(),
+                seed
+            );
+            return Ok(());
+        }
+        None => {}
+    }
+
+    // `adventofcode 5` is day 5 of the latest year rather than the year 5
+    let (year, day) = match (opt.year, opt.day) {
+        (Some(day), None) if day <= 25 => (Some(latest_year), Some(day)),
+        selection => selection,
+    };
+
+    if opt.input.is_some() {
+        anyhow::ensure!(day.is_some(), "--input requires a DAY");
+        anyhow::ensure!(!opt.verify, "--input can not be used with --verify");
+    }
+
+    let days: Vec<Puzzle> = match (year, day) {
+        (Some(year), Some(day)) => {
+            let solver = registry
+                .get(year, day)
+                .with_context(|| format!("{} day {} not yet solved!", year, day))?;
+            vec![(year, day, solver)]
+        }
+        (Some(year), None) => {
+            let days: Vec<Puzzle> = registry.year(year).collect();
+            anyhow::ensure!(!days.is_empty(), "No days of {} solved yet!", year);
+            days
+        }
+        _ => registry.iter().collect(),
+    };
+
+    check_modes(&opt, day)?;
+
+    if opt.watch {
+        return watch(&opt, &inputs, days[0]);
+    }
+
+    if opt.visualize || opt.export.is_some() {
+        return visualize(&opt, &inputs, &registry, days[0]);
+    }
+
+    if opt.verify {
+        return verify(&opt, &inputs, days);
+    }
+
+    if opt.mem {
+        mem::enable();
+        // one day at a time, the counters are shared by every thread
+        for (i, (year, day, solver)) in days.into_iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            let input = day_input(&opt, &inputs, year, day)?;
+            let report = solver
+                .solve_mem(&input, opt.part)
+                .with_context(|| format!("Solving {} day {:02}: {}", year, day, solver.name()))?;
+            mem::print_report(year, day, solver.name(), &report);
         }
+        return Ok(());
+    }
 
-        let input = day_input(day, opt.test)?;
-        let f = days[day - 1];
-        let solve = f(&input)?;
-        print_solve(day, solve);
-    } else {
-        for (day, f) in days.iter().enumerate() {
-            let day = day + 1;
-            let input = day_input(day, opt.test)?;
-            let solve = f(&input)?;
-            print_solve(day, solve);
-            println!();
+    if let Some(runs) = opt.bench {
+        for (i, (year, day, solver)) in days.into_iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            let input = day_input(&opt, &inputs, year, day)?;
+            let report = bench::bench(solver, &input, runs, opt.part)
+                .with_context(|| format!("Solving {} day {:02}: {}", year, day, solver.name()))?;
+            bench::print_report(year, day, solver.name(), &report);
         }
+        return Ok(());
+    }
+
+    let start = Instant::now();
+    let solved = pool::map_ordered(days, jobs(&opt), |puzzle| {
+        let cpu_start = pool::thread_cpu_time();
+        let record = solve(&opt, &inputs, puzzle);
+        let cpu = cpu_start.zip(pool::thread_cpu_time()).map(|(s, e)| e - s);
+        (record, cpu)
+    });
+    let wall = start.elapsed();
+    let cpu = solved.iter().map(|(_, cpu)| *cpu).sum::<Option<Duration>>();
+    let records: Vec<Record> = solved.into_iter().map(|(record, _)| record).collect();
+
+    output::write_records(&mut std::io::stdout().lock(), opt.format, &records)?;
+    if opt.format == Format::Text && records.len() > 1 {
+        println!();
+        output::print_summary(&records, wall, cpu);
+    }
+
+    // submissions are only made for our own real inputs
+    if !opt.test && opt.input.is_none() {
+        let submissions = Submissions::load(input::locate(&opt.submissions))?;
+        warn_submissions(&submissions, &records);
+    }
+
+    let failed = records.iter().filter(|r| r.error.is_some()).count();
+    if failed > 0 {
+        anyhow::bail!("{} day(s) failed", failed);
     }
+    Ok(())
+}
 
+// --watch, --visualize, --verify, --mem and --bench each run the days their own way, only one of
+// them can be given and only with the flags it supports
+fn check_modes(opt: &Opt, day: Option<usize>) -> Result<()> {
+    let modes: Vec<&str> = [
+        (opt.watch, "--watch"),
+        (opt.visualize || opt.export.is_some(), "--visualize"),
+        (opt.verify, "--verify"),
+        (opt.mem, "--mem"),
+        (opt.bench.is_some(), "--bench"),
+    ]
+    .into_iter()
+    .filter_map(|(given, name)| given.then_some(name))
+    .collect();
+    anyhow::ensure!(
+        modes.len() <= 1,
+        "{} can not be used together",
+        modes.join(" and ")
+    );
+
+    if opt.watch {
+        anyhow::ensure!(day.is_some(), "--watch requires a DAY");
+        anyhow::ensure!(
+            opt.format == Format::Text,
+            "--watch only supports text output"
+        );
+    }
+    if opt.visualize || opt.export.is_some() {
+        anyhow::ensure!(day.is_some(), "--visualize and --export require a DAY");
+    }
+    if opt.verify {
+        anyhow::ensure!(opt.part.is_none(), "--part can not be used with --verify");
+    }
+    if opt.mem {
+        anyhow::ensure!(
+            opt.format == Format::Text,
+            "--mem only supports text output"
+        );
+    }
+    if opt.bench.is_some() {
+        anyhow::ensure!(
+            opt.format == Format::Text,
+            "--bench only supports text output"
+        );
+    }
     Ok(())
 }
 
-fn day_input(day: usize, test_input: bool) -> Result<String> {
-    let fname = if test_input {
-        format!("inputs/{:0>2}.test.txt", day)
-    } else {
-        format!("inputs/{:0>2}.txt", day)
-    };
-    std::fs::read_to_string(&fname).with_context(|| format!("Reading file {}", fname))
+fn verify(opt: &Opt, inputs: &Inputs, days: Vec<Puzzle>) -> Result<()> {
+    let path = input::locate(&opt.answers);
+    let answers = Answers::load(&path)?;
+
+    let checks: Vec<_> = days
+        .into_iter()
+        .flat_map(|puzzle| [(puzzle, false), (puzzle, true)])
+        .collect();
+    let verdicts = pool::map_ordered(checks, jobs(opt), |((year, day, solver), test)| {
+        let result = inputs
+            .read(year, day, test)
+            .and_then(|input| solver.solve_timed(&input))
+            .map(|(solve, _)| solve);
+        let verdict = Verdict::new(answers.get(year, day, test), result);
+        (year, day, test, verdict)
+    });
+
+    let mut failed = 0;
+    for (year, day, test, verdict) in verdicts {
+        verdict.print(year, day, test);
+        if verdict.is_failure() {
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} answer(s) did not match {}", failed, path.display());
+    }
+    Ok(())
+}
+
+fn solve(opt: &Opt, inputs: &Inputs, (year, day, solver): Puzzle) -> Record {
+    let input = day_input(opt, inputs, year, day);
+    match opt.part {
+        Some(part) => Record::for_part(
+            year,
+            day,
+            solver.name(),
+            part,
+            input.and_then(|input| solver.solve_part(&input, part)),
+        ),
+        None => Record::new(
+            year,
+            day,
+            solver.name(),
+            input.and_then(|input| solver.solve_timed(&input)),
+        ),
+    }
+}
+
+fn watch(opt: &Opt, inputs: &Inputs, puzzle: Puzzle) -> Result<()> {
+    let (year, day, _) = puzzle;
+    let mut watcher = Watcher::new(inputs.year_dir(year), day);
+    if let Some(path) = &opt.input {
+        anyhow::ensure!(
+            path != Path::new("-"),
+            "--watch can not read the input from stdin"
+        );
+        watcher = watcher.watch_file(path);
+    }
+
+    let state = std::env::temp_dir().join(format!(
+        "adventofcode-watch-{}-{:02}{}.json",
+        year,
+        day,
+        if opt.test { ".test" } else { "" }
+    ));
+    watch::run(watcher, &state, || solve(opt, inputs, puzzle))
 }
 
-fn print_solve(day: usize, solve: SolveInfo) {
-    println!("--- Day {:02}: {} ---", day, solve.challenge);
-    println!("  Part 1: {}", solve.part01);
-    println!("  Part 2: {}", solve.part02);
+fn visualize(opt: &Opt, inputs: &Inputs, registry: &Registry, puzzle: Puzzle) -> Result<()> {
+    let (year, day, _) = puzzle;
+    let visualization = registry
+        .visualization(year, day)
+        .with_context(|| format!("{} day {} has no visualization", year, day))?;
+    anyhow::ensure!(opt.speed >= 0.0, "--speed can not be negative");
+
+    let input = day_input(opt, inputs, year, day)?;
+    if let Some(dir) = &opt.export {
+        let prefix = format!("{}-{:02}", year, day);
+        let mut exporter = Exporter::new(dir, prefix, opt.image_format, opt.scale, opt.speed)?;
+        visualization.visualize(&input, &mut exporter)?;
+        let written = exporter.finish()?;
+        println!("wrote {} file(s) to {}", written.len(), dir.display());
+        return Ok(());
+    }
+
+    let mut terminal = Terminal::new(opt.speed, !opt.no_color && visualize::color_enabled());
+    visualization.visualize(&input, &mut terminal)
+}
+
+// warnings go to stderr so that they don't end up in json or csv output
+fn warn_submissions(submissions: &Submissions, records: &[Record]) {
+    for record in records {
+        for (part, answer) in [(Part::One, &record.part01), (Part::Two, &record.part02)] {
+            let Some(answer) = answer else {
+                continue;
+            };
+            if let Some(warning) = submissions.check(record.year, record.day, part, answer) {
+                eprintln!(
+                    "warning: {} day {:02} part {} answer {}: {}",
+                    record.year,
+                    record.day,
+                    if part == Part::One { 1 } else { 2 },
+                    answer,
+                    warning
+                );
+            }
+        }
+    }
+}
+
+fn jobs(opt: &Opt) -> usize {
+    opt.jobs.unwrap_or_else(pool::default_jobs)
+}
+
+fn day_input(opt: &Opt, inputs: &Inputs, year: usize, day: usize) -> Result<String> {
+    match &opt.input {
+        Some(path) => input::read_input(path),
+        None => inputs.read(year, day, opt.test),
+    }
 }
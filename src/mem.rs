@@ -0,0 +1,176 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+// signed as blocks allocated before counting was enabled can still be freed
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// Global allocator that hands everything to [`System`] and counts allocations once [`enable`] has
+/// been called. The global allocator is fixed when the binary is built, so it is always installed
+/// and `--mem` only turns the counting on. A realloc counts as freeing the old block and allocating
+/// the new one.
+pub struct Counting;
+
+/// Starts counting allocations made through [`Counting`].
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+fn allocated(size: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn freed(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made during a single phase of a solve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Most bytes live at once on top of what was live when the phase started.
+    pub peak: usize,
+}
+
+pub struct MemReport {
+    pub parse: Usage,
+    pub part01: Option<Usage>,
+    pub part02: Option<Usage>,
+}
+
+// runs `f` and counts its allocations, the counters are global so this is only accurate while
+// nothing else is allocating on other threads
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let out = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: (PEAK.load(Ordering::Relaxed) - live).max(0) as usize,
+    };
+    (out, usage)
+}
+
+pub fn print_report(year: usize, day: usize, name: &str, report: &MemReport) {
+    println!("--- {} Day {:02}: {} ---", year, day, name);
+    println!(
+        "  {:<8} {:>12} {:>12} {:>12}",
+        "", "allocations", "allocated", "peak"
+    );
+    for (label, usage) in [
+        ("Parse", Some(report.parse)),
+        ("Part 1", report.part01),
+        ("Part 2", report.part02),
+    ] {
+        let Some(usage) = usage else {
+            continue;
+        };
+        println!(
+            "  {:<8} {:>12} {:>12} {:>12}",
+            label,
+            usage.allocations,
+            format_bytes(usage.bytes),
+            format_bytes(usage.peak),
+        );
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        const SIZE: usize = 64 << 20;
+        enable();
+        let ((), usage) = measure(|| {
+            let first: Vec<u8> = black_box(Vec::with_capacity(SIZE));
+            drop(first);
+            let second: Vec<u8> = black_box(Vec::with_capacity(SIZE));
+            drop(second);
+        });
+        // other tests allocate on their own threads meanwhile, far less than the buffers though
+        assert!(usage.allocations >= 2);
+        assert!(usage.bytes >= 2 * SIZE);
+        assert!((SIZE / 2..SIZE * 3 / 2).contains(&usage.peak));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("0 B", format_bytes(0));
+        assert_eq!("1023 B", format_bytes(1023));
+        assert_eq!("1.0 KiB", format_bytes(1024));
+        assert_eq!("7.6 MiB", format_bytes(8_000_000));
+        assert_eq!("2.0 GiB", format_bytes(2 << 30));
+    }
+}
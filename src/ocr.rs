@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+// letters drawn by puzzles that print their answer, each is 4 columns wide and 6 rows tall. letters
+// are separated by a blank column.
+const LETTERS: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+    (' ', ["....", "....", "....", "....", "....", "...."]),
+];
+
+const WIDTH: usize = 4;
+const HEIGHT: usize = 6;
+
+/// Reads the letters out of a rendering made of `#` (on) and any other character (off). Returns
+/// `None` if the rendering isn't made up entirely of known letters.
+pub fn read_letters(render: &str) -> Option<String> {
+    let glyphs: HashMap<Vec<bool>, char> = LETTERS
+        .iter()
+        .map(|(c, rows)| {
+            (
+                rows.iter()
+                    .flat_map(|r| r.chars().map(|c| c == '#'))
+                    .collect(),
+                *c,
+            )
+        })
+        .collect();
+
+    let rows: Vec<Vec<bool>> = render
+        .lines()
+        .map(|l| l.chars().map(|c| c == '#').collect())
+        .collect();
+    if rows.len() != HEIGHT {
+        return None;
+    }
+
+    // pad rows to a whole number of letters, trailing blank columns are often trimmed
+    let letters = (rows.iter().map(|r| r.len()).max()? + 1).div_ceil(WIDTH + 1);
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+
+    let mut text = String::new();
+    for i in 0..letters {
+        let x0 = i * (WIDTH + 1);
+        let glyph: Vec<bool> = (0..HEIGHT)
+            .flat_map(|y| (x0..x0 + WIDTH).map(move |x| (x, y)))
+            .map(|(x, y)| lit(x, y))
+            .collect();
+        text.push(*glyphs.get(&glyph)?);
+    }
+
+    let text = text.trim_end().to_owned();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_letters() {
+        let render = "\
+#..#..###
+#..#...#.
+####...#.
+#..#...#.
+#..#...#.
+#..#..###";
+        assert_eq!(Some("HI".to_owned()), read_letters(render));
+    }
+
+    #[test]
+    fn test_read_letters_unknown() {
+        assert_eq!(None, read_letters("#####\n#...#\n#...#\n#...#\n#####"));
+        assert_eq!(None, read_letters(""));
+    }
+}
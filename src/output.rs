@@ -0,0 +1,362 @@
+use std::{io::Write, str::FromStr, time::Duration};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    answer::Answer,
+    challenge::{Part, SolveInfo, Timings},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format {} (expected text, json or csv)", s)),
+        }
+    }
+}
+
+/// The outcome of solving a single day, flattened so that it can be written in any format. Parts
+/// that were not run are left empty.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: usize,
+    pub day: usize,
+    pub challenge: &'static str,
+    pub part01: Option<Answer>,
+    pub part02: Option<Answer>,
+    pub timings: Option<TimingsRecord>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TimingsRecord {
+    pub parse_ns: u128,
+    pub part01_ns: Option<u128>,
+    pub part02_ns: Option<u128>,
+}
+
+impl TimingsRecord {
+    fn new(t: Timings, part: Option<Part>) -> Self {
+        Self {
+            parse_ns: t.parse.as_nanos(),
+            part01_ns: Part::One.runs(part).then_some(t.part01.as_nanos()),
+            part02_ns: Part::Two.runs(part).then_some(t.part02.as_nanos()),
+        }
+    }
+
+    fn total_ns(&self) -> u128 {
+        self.parse_ns + self.part01_ns.unwrap_or(0) + self.part02_ns.unwrap_or(0)
+    }
+}
+
+impl Record {
+    pub fn new(
+        year: usize,
+        day: usize,
+        challenge: &'static str,
+        result: Result<(SolveInfo, Timings)>,
+    ) -> Self {
+        match result {
+            Ok((solve, timings)) => Record {
+                year,
+                day,
+                challenge: solve.challenge,
+                part01: Some(solve.part01),
+                part02: Some(solve.part02),
+                timings: Some(TimingsRecord::new(timings, None)),
+                error: None,
+            },
+            Err(e) => Record::failed(year, day, challenge, e),
+        }
+    }
+
+    /// A record for a solve where only `part` was run.
+    pub fn for_part(
+        year: usize,
+        day: usize,
+        challenge: &'static str,
+        part: Part,
+        result: Result<(Answer, Timings)>,
+    ) -> Self {
+        match result {
+            Ok((answer, timings)) => {
+                let (part01, part02) = match part {
+                    Part::One => (Some(answer), None),
+                    Part::Two => (None, Some(answer)),
+                };
+                Record {
+                    year,
+                    day,
+                    challenge,
+                    part01,
+                    part02,
+                    timings: Some(TimingsRecord::new(timings, Some(part))),
+                    error: None,
+                }
+            }
+            Err(e) => Record::failed(year, day, challenge, e),
+        }
+    }
+
+    fn failed(year: usize, day: usize, challenge: &'static str, e: anyhow::Error) -> Self {
+        Record {
+            year,
+            day,
+            challenge,
+            part01: None,
+            part02: None,
+            timings: None,
+            error: Some(format!("{:#}", e)),
+        }
+    }
+}
+
+pub fn write_records(out: &mut impl Write, format: Format, records: &[Record]) -> Result<()> {
+    match format {
+        Format::Text => write_text(out, records),
+        Format::Json => write_json(out, records),
+        Format::Csv => write_csv(out, records),
+    }
+}
+
+fn write_text(out: &mut impl Write, records: &[Record]) -> Result<()> {
+    for (i, record) in records.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(
+            out,
+            "--- {} Day {:02}: {} ---",
+            record.year, record.day, record.challenge
+        )?;
+        if let Some(error) = &record.error {
+            writeln!(out, "  Error: {}", error)?;
+            continue;
+        }
+
+        let t = record.timings.as_ref();
+        let timing = |ns: Option<u128>| ns.map_or(String::new(), |ns| format!(" ({})", fmt_ns(ns)));
+        write_part(
+            out,
+            "Part 1",
+            &record.part01,
+            timing(t.and_then(|t| t.part01_ns)),
+        )?;
+        write_part(
+            out,
+            "Part 2",
+            &record.part02,
+            timing(t.and_then(|t| t.part02_ns)),
+        )?;
+        if let Some(t) = t {
+            writeln!(
+                out,
+                "  Parse: {}, Total: {}",
+                fmt_ns(t.parse_ns),
+                fmt_ns(t.total_ns())
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// multi-line answers start on the line after the label and are indented below it, parts that were
+// not run are skipped
+fn write_part(
+    out: &mut impl Write,
+    label: &str,
+    answer: &Option<Answer>,
+    timing: String,
+) -> Result<()> {
+    match answer {
+        Some(answer) if answer.is_multiline() => {
+            writeln!(out, "  {}:{}", label, timing)?;
+            for line in answer.to_string().lines() {
+                writeln!(out, "    {}", line)?;
+            }
+        }
+        Some(answer) => writeln!(out, "  {}: {}{}", label, answer, timing)?,
+        None => {}
+    }
+    Ok(())
+}
+
+/// Compares the wall time of the whole run with the CPU time spent solving the days, which shows how
+/// much running the days concurrently saved. Without a CPU time the wall time of each day is summed
+/// instead, which overstates the saving when days share a core.
+pub fn print_summary(records: &[Record], wall: Duration, cpu: Option<Duration>) {
+    let (summed, label) = match cpu {
+        Some(cpu) => (cpu, "CPU"),
+        None => {
+            let summed: u128 = records
+                .iter()
+                .flat_map(|r| &r.timings)
+                .map(|t| t.total_ns())
+                .sum();
+            (Duration::from_nanos(summed as u64), "summed day wall time")
+        }
+    };
+    println!(
+        "Total: {:.2?} wall, {:.2?} {} across {} days ({:.1}x)",
+        wall,
+        summed,
+        label,
+        records.len(),
+        summed.as_secs_f64() / wall.as_secs_f64()
+    );
+}
+
+fn fmt_ns(ns: u128) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns as u64))
+}
+
+fn write_json(out: &mut impl Write, records: &[Record]) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *out, records)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_csv(out: &mut impl Write, records: &[Record]) -> Result<()> {
+    writeln!(
+        out,
+        "year,day,challenge,part01,part02,parse_ns,part01_ns,part02_ns,error"
+    )?;
+    for record in records {
+        let opt = |v: &Option<Answer>| {
+            v.as_ref()
+                .map_or(String::new(), |v| csv_field(&v.to_string()))
+        };
+        let ns = |v: Option<u128>| v.map(|v| v.to_string()).unwrap_or_default();
+        let (parse, part01, part02) = match &record.timings {
+            Some(t) => (t.parse_ns.to_string(), ns(t.part01_ns), ns(t.part02_ns)),
+            None => Default::default(),
+        };
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            csv_field(record.challenge),
+            opt(&record.part01),
+            opt(&record.part02),
+            parse,
+            part01,
+            part02,
+            csv_field(record.error.as_deref().unwrap_or_default()),
+        )?;
+    }
+    Ok(())
+}
+
+// quotes a field if it contains characters that are special in csv
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                year: 2021,
+                day: 1,
+                challenge: "Sonar Sweep",
+                part01: Some(Answer::Number(7)),
+                part02: Some(Answer::Render("#.\n.#\n".to_owned())),
+                timings: Some(TimingsRecord {
+                    parse_ns: 1,
+                    part01_ns: Some(2),
+                    part02_ns: Some(3),
+                }),
+                error: None,
+            },
+            Record {
+                year: 2021,
+                day: 2,
+                challenge: "Dive!",
+                part01: None,
+                part02: None,
+                timings: None,
+                error: Some("bad input, \"oops\"".to_owned()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = Vec::new();
+        write_records(&mut out, Format::Csv, &records()).unwrap();
+        assert_eq!(
+            "year,day,challenge,part01,part02,parse_ns,part01_ns,part02_ns,error\n\
+             2021,1,Sonar Sweep,7,\"#.\n.#\n\",1,2,3,\n\
+             2021,2,Dive!,,,,,,\"bad input, \"\"oops\"\"\"\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        let mut out = Vec::new();
+        write_records(&mut out, Format::Text, &records()).unwrap();
+        assert_eq!(
+            "--- 2021 Day 01: Sonar Sweep ---\n  \
+             Part 1: 7 (2.00ns)\n  \
+             Part 2: (3.00ns)\n    #.\n    .#\n  \
+             Parse: 1.00ns, Total: 6.00ns\n\
+             \n\
+             --- 2021 Day 02: Dive! ---\n  \
+             Error: bad input, \"oops\"\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let mut out = Vec::new();
+        write_records(&mut out, Format::Json, &records()).unwrap();
+        let v: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(7, v[0]["part01"]);
+        assert_eq!("#.\n.#\n", v[0]["part02"]);
+        assert_eq!(3, v[0]["timings"]["part02_ns"]);
+        assert!(v[1]["part01"].is_null());
+        assert_eq!("bad input, \"oops\"", v[1]["error"]);
+    }
+
+    #[test]
+    fn test_part() {
+        let timings = Timings {
+            parse: Duration::from_nanos(1),
+            part01: Duration::ZERO,
+            part02: Duration::from_nanos(3),
+        };
+        let record = Record::for_part(2021, 1, "Sonar Sweep", Part::Two, Ok((7.into(), timings)));
+
+        let mut out = Vec::new();
+        write_records(&mut out, Format::Text, &[record]).unwrap();
+        assert_eq!(
+            "--- 2021 Day 01: Sonar Sweep ---\n  \
+             Part 2: 7 (3.00ns)\n  \
+             Parse: 1.00ns, Total: 4.00ns\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::grid::Grid;
+
+/// An error produced while parsing a day's puzzle input. The position is 1-based and points at the
+/// token that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: usize,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token` which must be a subslice of `src`, the token's position within
+    /// `src` determines the line and column of the error.
+    pub fn new(day: usize, src: &str, token: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(src, token);
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+            found: token.to_owned(),
+        }
+    }
+
+    /// Repositions an error that was produced while parsing `part` on its own so that it is
+    /// relative to `src`, the larger input that `part` was sliced from.
+    pub fn within(mut self, src: &str, part: &str) -> Self {
+        let (line, column) = position(src, part);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02} line {} column {}: expected {}, found ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        match self.found.lines().next() {
+            None | Some("") => write!(f, "end of line"),
+            Some(found) if found.chars().count() > 20 => {
+                write!(f, "`{}...`", found.chars().take(20).collect::<String>())
+            }
+            Some(found) => write!(f, "`{}`", found),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// 1-based line and column of `token` within `src`
+fn position(src: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize).saturating_sub(src.as_ptr() as usize);
+    debug_assert!(offset <= src.len(), "token is not a slice of src");
+    let before = &src[..offset.min(src.len())];
+
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// Parses `token` as a number, reporting `expected` on failure.
+pub fn number<T: FromStr>(
+    day: usize,
+    src: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(day, src, token, expected))
+}
+
+/// Splits `s` on the first occurrence of `delim`, reporting the missing delimiter on failure.
+pub fn split_once<'a>(
+    day: usize,
+    src: &str,
+    s: &'a str,
+    delim: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delim)
+        .ok_or_else(|| ParseError::new(day, src, s, format!("`{}`", delim)))
+}
+
+/// Splits `s` on `delim` and parses each item with `item`, surrounding whitespace of `s` is
+/// ignored. The errors of `item` must be relative to the same source as the item itself.
+pub fn list_with<'a, T>(
+    s: &'a str,
+    delim: &str,
+    item: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.trim().split(delim).map(item).collect()
+}
+
+/// Parses a `delim` separated list of numbers such as `3,4,3,1,2`.
+pub fn numbers<T: FromStr>(
+    day: usize,
+    src: &str,
+    s: &str,
+    delim: &str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    list_with(s, delim, |token| number(day, src, token, expected))
+}
+
+/// Parses every line of `src` with `T`'s `FromStr`, errors are repositioned relative to `src`.
+pub fn lines<T>(src: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    src.lines()
+        .map(|line| line.parse().map_err(|e: ParseError| e.within(src, line)))
+        .collect()
+}
+
+/// Splits `s` into its sections, which are separated by blank lines.
+pub fn sections(s: &str) -> impl Iterator<Item = &str> {
+    s.trim_end().split("\n\n")
+}
+
+/// Splits `s` into exactly two sections separated by a blank line, e.g. a header and a list of
+/// rules.
+pub fn two_sections<'a>(
+    day: usize,
+    src: &str,
+    s: &'a str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    let (first, second) = s
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(day, src, end_of(s), "blank line between sections"))?;
+    if second.trim_end().contains("\n\n") {
+        let third = second.trim_end().split("\n\n").nth(1).unwrap_or_default();
+        return Err(ParseError::new(day, src, third, "only two sections"));
+    }
+    Ok((first, second))
+}
+
+/// Parses an inclusive range written as `key=start..end`, e.g. `x=20..30`.
+pub fn key_range<T: FromStr>(
+    day: usize,
+    src: &str,
+    s: &str,
+    key: &str,
+) -> Result<RangeInclusive<T>, ParseError> {
+    let range = s
+        .strip_prefix(key)
+        .and_then(|s| s.strip_prefix('='))
+        .ok_or_else(|| ParseError::new(day, src, s, format!("`{}=`", key)))?;
+    let (start, end) = split_once(day, src, range, "..")?;
+    Ok(number(day, src, start, "integer")?..=number(day, src, end, "integer")?)
+}
+
+/// Splits a rule written as `left -> right`.
+pub fn rule<'a>(day: usize, src: &str, s: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    split_once(day, src, s, " -> ")
+}
+
+/// Parses a rectangular grid of single digits, one row per line.
+pub fn digit_grid(day: usize, input: &str) -> Result<Grid<u32>, ParseError> {
+    let mut grid: Vec<Vec<u32>> = Vec::new();
+    for line in input.lines() {
+        let mut row = Vec::with_capacity(line.len());
+        for (i, c) in line.char_indices() {
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| ParseError::new(day, input, &line[i..i + c.len_utf8()], "digit"))?;
+            row.push(digit);
+        }
+
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                let expected = format!("row of {} digits", first.len());
+                return Err(ParseError::new(day, input, line, expected));
+            }
+        }
+        grid.push(row);
+    }
+
+    if grid.is_empty() || grid[0].is_empty() {
+        return Err(ParseError::new(day, input, end_of(input), "digit"));
+    }
+    Ok(Grid::from_rows(grid).expect("rows have the same length"))
+}
+
+/// An empty slice positioned at the end of `s`, used to report input that ended too early.
+pub fn end_of(s: &str) -> &str {
+    &s[s.len()..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let src = "abc\ndef ghi\n";
+        let err = ParseError::new(1, src, &src[8..11], "number");
+        assert_eq!((2, 5), (err.line, err.column));
+        assert_eq!(
+            "day 01 line 2 column 5: expected number, found `ghi`",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_within() {
+        let src = "abc\ndef ghi\n";
+        let line = src.lines().nth(1).unwrap();
+        let err = ParseError::new(1, line, &line[4..], "number").within(src, line);
+        assert_eq!((2, 5), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]),
+            digit_grid(9, "12\n34\n").ok()
+        );
+
+        let err = digit_grid(9, "12\n3x\n").unwrap_err();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.found.as_str()));
+
+        let err = digit_grid(9, "12\n345\n").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+        assert_eq!("row of 2 digits", err.expected);
+    }
+
+    #[test]
+    fn test_end_of() {
+        let src = "12,";
+        let (_, rest) = split_once(3, src, src, ",").unwrap();
+        let err = number::<i64>(3, src, end_of(rest), "number").unwrap_err();
+        assert_eq!((1, 4), (err.line, err.column));
+        assert_eq!(
+            "day 03 line 1 column 4: expected number, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        let src = "3,4,3,1,2\n";
+        assert_eq!(
+            Ok(vec![3, 4, 3, 1, 2]),
+            numbers::<u8>(6, src, src, ",", "timer")
+        );
+
+        let src = "1\n2\nx\n";
+        let err = numbers::<i64>(1, src, src, "\n", "integer").unwrap_err();
+        assert_eq!((3, 1, "x"), (err.line, err.column, err.found.as_str()));
+
+        let err = numbers::<i64>(1, "", "", "\n", "integer").unwrap_err();
+        assert_eq!(
+            "day 01 line 1 column 1: expected integer, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let src = "a\nb\n\nc\n\nd\n";
+        assert_eq!(vec!["a\nb", "c", "d"], sections(src).collect::<Vec<_>>());
+
+        let err = two_sections(14, src, src).unwrap_err();
+        assert_eq!((6, 1, "d"), (err.line, err.column, err.found.as_str()));
+        assert_eq!(Ok(("a", "b\n")), two_sections(14, "a\n\nb\n", "a\n\nb\n"));
+        let err = two_sections(14, "a\nb\n", "a\nb\n").unwrap_err();
+        assert_eq!((3, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_key_range() {
+        let src = "target area: x=20..30, y=-10..-5";
+        assert_eq!(Ok(20..=30), key_range(17, src, &src[13..21], "x"));
+        assert_eq!(Ok(-10..=-5), key_range(17, src, &src[23..], "y"));
+
+        let err = key_range::<i32>(17, src, &src[23..], "x").unwrap_err();
+        assert_eq!(
+            (1, 24, "`x=`"),
+            (err.line, err.column, err.expected.as_str())
+        );
+        let err = key_range::<i32>(17, src, &src[13..17], "x").unwrap_err();
+        assert_eq!("`..`", err.expected);
+    }
+
+    #[test]
+    fn test_rule() {
+        let src = "CH -> B\nHH N\n";
+        let mut lines = src.lines();
+        assert_eq!(Ok(("CH", "B")), rule(14, src, lines.next().unwrap()));
+        let err = rule(14, src, lines.next().unwrap()).unwrap_err();
+        assert_eq!(
+            (2, 1, "` -> `"),
+            (err.line, err.column, err.expected.as_str())
+        );
+    }
+}
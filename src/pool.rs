@@ -0,0 +1,98 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// Number of workers to use when none was requested, one per available core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// CPU time used by the calling thread so far, where the platform can tell.
+pub fn thread_cpu_time() -> Option<Duration> {
+    #[cfg(unix)]
+    {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `ts` is a valid timespec for clock_gettime to write to
+        let ret = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+        (ret == 0).then(|| Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+/// Runs `f` over every item on a pool of `jobs` worker threads. Results are returned in the same
+/// order as the items regardless of which finished first.
+pub fn map_ordered<T, R, F>(items: Vec<T>, jobs: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let len = items.len();
+    let jobs = jobs.clamp(1, len.max(1));
+
+    // workers claim the next unprocessed item by bumping a shared index
+    let next = AtomicUsize::new(0);
+    let items: Vec<Mutex<Option<T>>> = items.into_iter().map(|t| Mutex::new(Some(t))).collect();
+    let results: Vec<Mutex<Option<R>>> = (0..len).map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|s| {
+        for _ in 0..jobs {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= len {
+                    break;
+                }
+                let item = items[i].lock().unwrap().take().unwrap();
+                let result = f(item);
+                *results[i].lock().unwrap() = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|r| r.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn test_thread_cpu_time() {
+        // sleeping takes no CPU time, unlike the wall time of a day
+        let start = thread_cpu_time().unwrap();
+        thread::sleep(Duration::from_millis(50));
+        assert!(thread_cpu_time().unwrap() - start < Duration::from_millis(25));
+    }
+
+    #[test]
+    fn test_map_ordered() {
+        let items: Vec<u64> = (0..50).collect();
+        // make early items slower so that they finish last
+        let results = map_ordered(items, 4, |n| {
+            thread::sleep(std::time::Duration::from_micros(50 - n));
+            n * 2
+        });
+        assert_eq!((0..50).map(|n| n * 2).collect::<Vec<_>>(), results);
+    }
+
+    #[test]
+    fn test_map_ordered_empty() {
+        let results: Vec<u8> = map_ordered(Vec::<u8>::new(), 4, |n| n);
+        assert!(results.is_empty());
+    }
+}
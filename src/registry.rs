@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use crate::{challenge::Challenge, gen::Generate, visualize::Visualization};
+
+/// All solved days keyed by year and day number.
+pub struct Registry {
+    solvers: BTreeMap<(usize, usize), Box<dyn Challenge>>,
+    visualizations: BTreeMap<(usize, usize), Box<dyn Visualization>>,
+    generators: BTreeMap<(usize, usize), Box<dyn Generate>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        let mut registry = Self {
+            solvers: BTreeMap::new(),
+            visualizations: BTreeMap::new(),
+            generators: BTreeMap::new(),
+        };
+        crate::y2021::register(&mut registry);
+        registry
+    }
+
+    pub fn register(&mut self, year: usize, day: usize, solver: impl Challenge + 'static) {
+        self.solvers.insert((year, day), Box::new(solver));
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Option<&dyn Challenge> {
+        self.solvers.get(&(year, day)).map(|s| s.as_ref())
+    }
+
+    /// Registers the visualization of a day, the day itself is registered with `register`.
+    pub fn register_visualization(
+        &mut self,
+        year: usize,
+        day: usize,
+        visualization: impl Visualization + 'static,
+    ) {
+        self.visualizations
+            .insert((year, day), Box::new(visualization));
+    }
+
+    pub fn visualization(&self, year: usize, day: usize) -> Option<&dyn Visualization> {
+        self.visualizations.get(&(year, day)).map(|v| v.as_ref())
+    }
+
+    /// Registers the input generator of a day, the day itself is registered with `register`.
+    pub fn register_generator(
+        &mut self,
+        year: usize,
+        day: usize,
+        generator: impl Generate + 'static,
+    ) {
+        self.generators.insert((year, day), Box::new(generator));
+    }
+
+    pub fn generator(&self, year: usize, day: usize) -> Option<&dyn Generate> {
+        self.generators.get(&(year, day)).map(|g| g.as_ref())
+    }
+
+    /// Every day with an input generator ordered by year and then day.
+    pub fn generators(&self) -> impl Iterator<Item = (usize, usize, &dyn Generate)> {
+        self.generators
+            .iter()
+            .map(|((year, day), g)| (*year, *day, g.as_ref()))
+    }
+
+    /// Every solved day ordered by year and then day.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &dyn Challenge)> {
+        self.solvers
+            .iter()
+            .map(|((year, day), s)| (*year, *day, s.as_ref()))
+    }
+
+    /// The solved days of a single year.
+    pub fn year(&self, year: usize) -> impl Iterator<Item = (usize, usize, &dyn Challenge)> {
+        self.iter().filter(move |(y, _, _)| *y == year)
+    }
+
+    /// The most recent year with at least one solved day.
+    pub fn latest_year(&self) -> Option<usize> {
+        self.solvers.keys().next_back().map(|(year, _)| *year)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
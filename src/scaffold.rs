@@ -0,0 +1,384 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::input::Inputs;
+
+// module skeleton for a new day, `__YEAR__`, `__DAY__`, `__PAD__` and `__TITLE__` are substituted
+const TEMPLATE: &str = r#"use crate::{
+    answer::Answer,
+    challenge::Solver,
+    parse::{self, ParseError},
+};
+
+const DAY: usize = __DAY__;
+
+pub struct Day__PAD__;
+
+impl Solver for Day__PAD__ {
+    type Input<'a> = Vec<&'a str>;
+
+    fn name(&self) -> &'static str {
+        __TITLE__
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input)?.into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part02(input)?.into())
+    }
+}
+
+pub fn part01(_lines: &[&str]) -> anyhow::Result<i64> {
+    anyhow::bail!("part 1 not yet solved")
+}
+
+pub fn part02(_lines: &[&str]) -> anyhow::Result<i64> {
+    anyhow::bail!("part 2 not yet solved")
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.is_empty() {
+        return Err(ParseError::new(DAY, input, parse::end_of(input), "input"));
+    }
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "fill in the example answer from the puzzle description"]
+    fn test_part01() {
+        let lines = parse_input(include_str!("../../inputs/__YEAR__/__PAD__.test.txt")).unwrap();
+        assert_eq!(0, part01(&lines).unwrap());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse_input("").is_err());
+    }
+}
+"#;
+
+// module for a year that doesn't exist yet, holding its first day
+const YEAR_TEMPLATE: &str = r#"//! Advent of Code __YEAR__.
+
+use crate::registry::Registry;
+
+pub mod day__PAD__;
+
+pub const YEAR: usize = __YEAR__;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(YEAR, __DAY__, day__PAD__::Day__PAD__);
+}
+"#;
+
+/// Generates the files for a new day within a checkout of this crate.
+pub struct Scaffold {
+    root: PathBuf,
+}
+
+impl Scaffold {
+    /// `root` is the crate directory containing `src`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Writes `src/yYYYY/dayNN.rs`, declares and registers it in `src/yYYYY/mod.rs` and creates
+    /// empty real and test inputs. The year module is created, declared in `lib.rs` and registered
+    /// in `registry.rs` if this is the first day of the year. Returns the paths that were created
+    /// or modified.
+    pub fn new_day(
+        &self,
+        year: usize,
+        day: usize,
+        title: &str,
+        inputs: &Inputs,
+    ) -> Result<Vec<PathBuf>> {
+        anyhow::ensure!((1..=25).contains(&day), "day must be between 1 and 25");
+        anyhow::ensure!(year >= 2015, "there is no Advent of Code {}", year);
+
+        let src = self.root.join("src");
+        let year_dir = src.join(format!("y{}", year));
+        let module = year_dir.join(format!("day{:02}.rs", day));
+        anyhow::ensure!(!module.exists(), "{} already exists", module.display());
+
+        // make every edit in memory first so that nothing is written if any of them fail
+        let year_mod = year_dir.join("mod.rs");
+        let mut edits = vec![(module, render(TEMPLATE, year, day, title))];
+        if year_mod.exists() {
+            let year_src = add_mod(&read(&year_mod)?, &format!("day{:02}", day))
+                .and_then(|s| register_day(&s, day))
+                .with_context(|| format!("Editing {}", year_mod.display()))?;
+            edits.push((year_mod, year_src));
+        } else {
+            let lib = src.join("lib.rs");
+            let lib_src = add_mod(&read(&lib)?, &format!("y{}", year))
+                .with_context(|| format!("Editing {}", lib.display()))?;
+            let registry = src.join("registry.rs");
+            let registry_src = register_year(&read(&registry)?, year)
+                .with_context(|| format!("Editing {}", registry.display()))?;
+            edits.push((year_mod, render(YEAR_TEMPLATE, year, day, title)));
+            edits.push((lib, lib_src));
+            edits.push((registry, registry_src));
+        }
+
+        fs::create_dir_all(&year_dir)
+            .with_context(|| format!("Creating directory {}", year_dir.display()))?;
+        let mut written = Vec::new();
+        for (path, contents) in edits {
+            write(&path, &contents)?;
+            written.push(path);
+        }
+
+        for test in [false, true] {
+            let path = inputs.path(year, day, test);
+            if !path.exists() {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)
+                        .with_context(|| format!("Creating directory {}", dir.display()))?;
+                }
+                write(&path, "")?;
+                written.push(path);
+            }
+        }
+        Ok(written)
+    }
+}
+
+fn render(template: &str, year: usize, day: usize, title: &str) -> String {
+    template
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &day.to_string())
+        .replace("__PAD__", &format!("{:02}", day))
+        .replace("__TITLE__", &format!("{:?}", title))
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Reading file {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("Writing file {}", path.display()))
+}
+
+// inserts `pub mod name;` into the alphabetically sorted module declarations
+fn add_mod(src: &str, name: &str) -> Result<String> {
+    let decl = format!("pub mod {};", name);
+    let mut lines: Vec<&str> = src.lines().collect();
+    let first = lines
+        .iter()
+        .position(|l| l.starts_with("pub mod "))
+        .context("no module declarations found")?;
+    let count = lines[first..]
+        .iter()
+        .take_while(|l| l.starts_with("pub mod "))
+        .count();
+
+    let mods = &lines[first..first + count];
+    anyhow::ensure!(
+        !mods.contains(&decl.as_str()),
+        "{} is already declared",
+        name
+    );
+    let at = first + mods.iter().take_while(|l| **l < decl.as_str()).count();
+    lines.insert(at, &decl);
+    Ok(lines.join("\n") + "\n")
+}
+
+// inserts `line` among the lines starting with `prefix` followed by a number, keeping them sorted
+// by that number. The line goes right before `end` if its number is the highest.
+fn insert_sorted(src: &str, prefix: &str, n: usize, end: &str, line: &str) -> Result<String> {
+    let end = src
+        .find(end)
+        .with_context(|| format!("{:?} not found", end))?;
+    let number = |i: usize| {
+        let rest = &src[i + prefix.len()..];
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        rest[..digits].parse::<usize>().ok()
+    };
+    let existing: Vec<(usize, Option<usize>)> = src[..end]
+        .match_indices(prefix)
+        .map(|(i, _)| (i, number(i)))
+        .collect();
+    anyhow::ensure!(
+        !existing.iter().any(|(_, m)| *m == Some(n)),
+        "{} is already registered",
+        n
+    );
+    let at = match existing.iter().find(|(_, m)| m.is_some_and(|m| m > n)) {
+        Some((i, _)) => *i,
+        // right after the last existing line, anything that follows it stays below
+        None => existing.last().map_or(end, |(i, _)| {
+            src[*i..end]
+                .find('\n')
+                .map_or(end, |newline| i + newline + 1)
+        }),
+    };
+    Ok(format!("{}{}\n{}", &src[..at], line, &src[at..]))
+}
+
+// adds `registry.register(YEAR, NN, dayNN::DayNN);` to the end of a year's `register`
+fn register_day(year_src: &str, day: usize) -> Result<String> {
+    let start = year_src
+        .find("pub fn register(")
+        .context("no register function found")?;
+    let (head, register) = year_src.split_at(start);
+    let line = format!(
+        "    registry.register(YEAR, {}, day{:02}::Day{:02});",
+        day, day, day
+    );
+    let register = insert_sorted(register, "    registry.register(YEAR, ", day, "}", &line)?;
+    Ok(format!("{}{}", head, register))
+}
+
+// calls a new year's `register` from `Registry::new`
+fn register_year(registry: &str, year: usize) -> Result<String> {
+    let line = format!("        crate::y{}::register(&mut registry);", year);
+    insert_sorted(
+        registry,
+        "        crate::y",
+        year,
+        "        registry\n    }",
+        &line,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "//! docs\n\npub mod answer;\npub mod registry;\npub mod y2021;\n";
+
+    const REGISTRY: &str = "\
+impl Registry {
+    pub fn new() -> Self {
+        let mut registry = Self {
+            solvers: BTreeMap::new(),
+        };
+        crate::y2021::register(&mut registry);
+        registry
+    }
+}
+";
+
+    const YEAR: &str = "\
+//! Advent of Code 2021.
+
+use crate::registry::Registry;
+
+pub mod day01;
+pub mod day15;
+
+pub const YEAR: usize = 2021;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(YEAR, 1, day01::Day01);
+    registry.register(YEAR, 15, day15::Day15);
+
+    registry.register_visualization(YEAR, 15, day15::Day15);
+}
+";
+
+    #[test]
+    fn test_add_mod() {
+        assert_eq!(
+            "//! docs\n\npub mod answer;\npub mod registry;\npub mod y2015;\npub mod y2021;\n",
+            add_mod(LIB, "y2015").unwrap()
+        );
+        assert!(add_mod(LIB, "y2021").is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let updated = add_mod(YEAR, "day03")
+            .and_then(|s| register_day(&s, 3))
+            .unwrap();
+        let expected = YEAR
+            .replace("day01;\n", "day01;\npub mod day03;\n")
+            .replace(
+                "Day01);\n",
+                "Day01);\n    registry.register(YEAR, 3, day03::Day03);\n",
+            );
+        assert_eq!(expected, updated);
+
+        let updated = register_day(YEAR, 18).unwrap();
+        assert!(updated.contains("Day15);\n    registry.register(YEAR, 18, day18::Day18);\n\n"));
+
+        assert!(register_day(YEAR, 15).is_err());
+    }
+
+    #[test]
+    fn test_register_year() {
+        let updated = register_year(REGISTRY, 2015).unwrap();
+        assert!(updated.contains(
+            "        crate::y2015::register(&mut registry);\n        crate::y2021::register"
+        ));
+
+        let updated = register_year(REGISTRY, 2022).unwrap();
+        assert!(updated.contains(
+            "(&mut registry);\n        crate::y2022::register(&mut registry);\n        registry\n"
+        ));
+
+        assert!(register_year(REGISTRY, 2021).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2021")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/y2021/mod.rs"), YEAR).unwrap();
+        let inputs = Inputs::new(Some(root.join("inputs")));
+        let scaffold = Scaffold::new(&root);
+
+        let written = scaffold
+            .new_day(2021, 20, "Trench \"Map\"", &inputs)
+            .unwrap();
+        assert_eq!(4, written.len());
+
+        let module = fs::read_to_string(root.join("src/y2021/day20.rs")).unwrap();
+        assert!(module.contains("pub struct Day20;"));
+        assert!(module.contains("const DAY: usize = 20;"));
+        assert!(module.contains(r#""Trench \"Map\"""#));
+        assert!(module.contains("../../inputs/2021/20.test.txt"));
+        assert_eq!("", fs::read_to_string(inputs.path(2021, 20, true)).unwrap());
+        let year = fs::read_to_string(root.join("src/y2021/mod.rs")).unwrap();
+        assert!(year.contains("registry.register(YEAR, 20, day20::Day20);"));
+
+        // a second run must not clobber the day that was just created
+        assert!(scaffold.new_day(2021, 20, "again", &inputs).is_err());
+
+        // the first day of a year also creates the year
+        let written = scaffold
+            .new_day(2015, 1, "Not Quite Lisp", &inputs)
+            .unwrap();
+        assert_eq!(6, written.len());
+        let year = fs::read_to_string(root.join("src/y2015/mod.rs")).unwrap();
+        assert!(year.contains("pub mod day01;"));
+        assert!(year.contains("pub const YEAR: usize = 2015;"));
+        assert!(year.contains("registry.register(YEAR, 1, day01::Day01);"));
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod y2015;\npub mod y2021;"));
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains("crate::y2015::register(&mut registry);"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
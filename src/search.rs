@@ -0,0 +1,201 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// The edges leaving each node of a graph along with their costs. Implemented for closures
+/// returning the neighbors of a node, e.g. `|p| grid.neighbors4(p).map(|n| (n, 1))`.
+pub trait Neighbors<N> {
+    fn neighbors(&self, node: N) -> impl Iterator<Item = (N, u64)>;
+}
+
+impl<N, F, I> Neighbors<N> for F
+where
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    fn neighbors(&self, node: N) -> impl Iterator<Item = (N, u64)> {
+        self(node).into_iter()
+    }
+}
+
+/// A path found by a search, `nodes` runs from the start to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: u64,
+    pub nodes: Vec<N>,
+}
+
+/// Every node reachable from `start` along with the number of edges to get there, edge costs are
+/// ignored.
+pub fn reachable<N>(graph: &impl Neighbors<N>, start: N) -> HashMap<N, u64>
+where
+    N: Copy + Eq + Hash,
+{
+    let mut steps = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let next = steps[&node] + 1;
+        for (neighbor, _) in graph.neighbors(node) {
+            if let Entry::Vacant(entry) = steps.entry(neighbor) {
+                entry.insert(next);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    steps
+}
+
+/// Breadth first search for the path with the fewest edges from `start` to a node matching
+/// `goal`, its cost is the number of edges.
+pub fn bfs<N>(graph: &impl Neighbors<N>, start: N, goal: impl Fn(&N) -> bool) -> Option<Path<N>>
+where
+    N: Copy + Eq + Hash,
+{
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            let nodes = walk_back(&parents, start, node);
+            return Some(Path {
+                cost: nodes.len() as u64 - 1,
+                nodes,
+            });
+        }
+        for (neighbor, _) in graph.neighbors(node) {
+            if neighbor != start {
+                if let Entry::Vacant(entry) = parents.entry(neighbor) {
+                    entry.insert(node);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's search for the cheapest path from `start` to a node matching `goal`.
+pub fn dijkstra<N>(
+    graph: &impl Neighbors<N>,
+    start: N,
+    goal: impl Fn(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Copy + Ord + Hash,
+{
+    astar(graph, start, goal, |_| 0)
+}
+
+/// A* search for the cheapest path from `start` to a node matching `goal`. `heuristic` estimates
+/// the cost left to reach the goal, it must never overestimate for the path to be the cheapest.
+pub fn astar<N>(
+    graph: &impl Neighbors<N>,
+    start: N,
+    goal: impl Fn(&N) -> bool,
+    heuristic: impl Fn(&N) -> u64,
+) -> Option<Path<N>>
+where
+    N: Copy + Ord + Hash,
+{
+    // cheapest known cost to each node
+    let mut costs = HashMap::from([(start, 0)]);
+    let mut parents = HashMap::new();
+
+    // ordered by the estimated total cost, the cheapest first
+    let mut queue = BinaryHeap::from([(Reverse(heuristic(&start)), Reverse(0), start)]);
+    while let Some((_, Reverse(cost), node)) = queue.pop() {
+        if goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: walk_back(&parents, start, node),
+            });
+        }
+        // a cheaper way to this node was already handled
+        if cost > costs[&node] {
+            continue;
+        }
+
+        for (neighbor, edge) in graph.neighbors(node) {
+            let next = cost + edge;
+            if costs.get(&neighbor).is_none_or(|&known| next < known) {
+                costs.insert(neighbor, next);
+                parents.insert(neighbor, node);
+                queue.push((
+                    Reverse(next + heuristic(&neighbor)),
+                    Reverse(next),
+                    neighbor,
+                ));
+            }
+        }
+    }
+    None
+}
+
+// follows the parents from `end` back to `start`, returning the nodes in path order
+fn walk_back<N: Copy + Eq + Hash>(parents: &HashMap<N, N>, start: N, end: N) -> Vec<N> {
+    let mut nodes = vec![end];
+    let mut current = end;
+    while current != start {
+        current = parents[&current];
+        nodes.push(current);
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1- 1 -1- 2 -1- 3
+    //  \----------5-----/
+    fn graph(node: u32) -> Vec<(u32, u64)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (3, 1)],
+            3 => vec![(2, 1), (0, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(&graph, 0, |&n| n == 3).unwrap();
+        assert_eq!((1, vec![0, 3]), (path.cost, path.nodes));
+        assert_eq!(None, bfs(&graph, 0, |&n| n == 4));
+
+        let steps = reachable(&graph, 1);
+        assert_eq!(4, steps.len());
+        assert_eq!(2, steps[&3]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(&graph, 0, |&n| n == 3).unwrap();
+        assert_eq!((3, vec![0, 1, 2, 3]), (path.cost, path.nodes));
+        assert_eq!(
+            Some(Path {
+                cost: 0,
+                nodes: vec![2]
+            }),
+            dijkstra(&graph, 2, |&n| n == 2)
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        // walking a 5x5 grid from corner to corner
+        let neighbors = |(x, y): (i32, i32)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+                .map(|p| (p, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| (4 - x + 4 - y) as u64;
+        let path = astar(&neighbors, (0, 0), |&p| p == (4, 4), manhattan).unwrap();
+        assert_eq!(8, path.cost);
+        assert_eq!(9, path.nodes.len());
+    }
+}
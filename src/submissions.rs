@@ -0,0 +1,267 @@
+use std::{collections::BTreeMap, fmt, path::Path, str::FromStr};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{answer::Answer, challenge::Part, verify};
+
+/// Every answer submitted for each day and part along with the feedback it got, stored in
+/// `submissions.toml` keyed by year and then day.
+#[derive(Debug, Default)]
+pub struct Submissions(BTreeMap<(usize, usize), DaySubmissions>);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DaySubmissions {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part01: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part02: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: Answer,
+    pub feedback: Feedback,
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Feedback {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl FromStr for Feedback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Feedback::Correct),
+            "wrong" => Ok(Feedback::Wrong),
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low" => Ok(Feedback::TooLow),
+            _ => Err(format!(
+                "unknown feedback {} (expected correct, wrong, too-high or too-low)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Feedback::Correct => "correct",
+            Feedback::Wrong => "wrong",
+            Feedback::TooHigh => "too high",
+            Feedback::TooLow => "too low",
+        })
+    }
+}
+
+/// Why a computed answer should not be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// A different answer was already accepted.
+    NotCorrect(Answer),
+    /// This exact answer was already submitted and rejected.
+    KnownWrong(Feedback),
+    /// At or below an answer that was too low.
+    TooLow(i64),
+    /// At or above an answer that was too high.
+    TooHigh(i64),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::NotCorrect(correct) => write!(f, "the accepted answer was {}", correct),
+            Warning::KnownWrong(feedback) => {
+                write!(f, "already submitted and was {}", feedback)
+            }
+            Warning::TooLow(bound) => write!(f, "{} was already too low", bound),
+            Warning::TooHigh(bound) => write!(f, "{} was already too high", bound),
+        }
+    }
+}
+
+impl Submissions {
+    /// Loads the submissions, a missing file is the same as an empty one.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let s = match std::fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("Reading file {}", path.display())),
+        };
+        Self::parse(&s).with_context(|| format!("Parsing submissions file {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut raw: BTreeMap<String, BTreeMap<String, &DaySubmissions>> = BTreeMap::new();
+        for ((year, day), subs) in &self.0 {
+            raw.entry(year.to_string())
+                .or_default()
+                .insert(format!("{:02}", day), subs);
+        }
+        let s = toml::to_string(&raw)?;
+        std::fs::write(path, s).with_context(|| format!("Writing file {}", path.display()))
+    }
+
+    fn parse(s: &str) -> Result<Self> {
+        Ok(Self(verify::by_year_and_day(toml::from_str(s)?)?))
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: Part) -> &[Submission] {
+        match (self.0.get(&(year, day)), part) {
+            (Some(subs), Part::One) => &subs.part01,
+            (Some(subs), Part::Two) => &subs.part02,
+            (None, _) => &[],
+        }
+    }
+
+    /// Records a submission, replacing the feedback if the answer was already recorded.
+    pub fn record(&mut self, year: usize, day: usize, part: Part, submission: Submission) {
+        let subs = self.0.entry((year, day)).or_default();
+        let subs = match part {
+            Part::One => &mut subs.part01,
+            Part::Two => &mut subs.part02,
+        };
+        match subs.iter_mut().find(|s| s.answer == submission.answer) {
+            Some(existing) => existing.feedback = submission.feedback,
+            None => subs.push(submission),
+        }
+    }
+
+    /// Checks a computed answer against what was already submitted. Returns `None` if nothing is
+    /// known to be wrong with it.
+    pub fn check(&self, year: usize, day: usize, part: Part, answer: &Answer) -> Option<Warning> {
+        let subs = self.get(year, day, part);
+
+        if let Some(correct) = subs.iter().find(|s| s.feedback == Feedback::Correct) {
+            return (correct.answer != *answer)
+                .then(|| Warning::NotCorrect(correct.answer.clone()));
+        }
+        if let Some(known) = subs.iter().find(|s| s.answer == *answer) {
+            return Some(Warning::KnownWrong(known.feedback));
+        }
+
+        let Answer::Number(n) = *answer else {
+            return None;
+        };
+        let bound = |feedback| {
+            subs.iter()
+                .filter(move |s| s.feedback == feedback)
+                .filter_map(|s| match s.answer {
+                    Answer::Number(n) => Some(n),
+                    _ => None,
+                })
+        };
+        if let Some(low) = bound(Feedback::TooLow).max().filter(|&low| n <= low) {
+            return Some(Warning::TooLow(low));
+        }
+        if let Some(high) = bound(Feedback::TooHigh).min().filter(|&high| n >= high) {
+            return Some(Warning::TooHigh(high));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submissions() -> Submissions {
+        Submissions::parse(
+            r#"
+            [2021.05]
+            part01 = [
+                { answer = 100, feedback = "too-low" },
+                { answer = 300, feedback = "too-high" },
+                { answer = 200, feedback = "wrong" },
+            ]
+            part02 = [
+                { answer = 7, feedback = "too-high" },
+                { answer = 5, feedback = "correct" },
+            ]
+
+            [2021.13]
+            part02 = [{ answer = "ABC", feedback = "wrong" }]
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check() {
+        let subs = submissions();
+        let check = |day, part, answer: Answer| subs.check(2021, day, part, &answer);
+
+        assert_eq!(None, check(5, Part::One, 150.into()));
+        assert_eq!(
+            Some(Warning::KnownWrong(Feedback::TooLow)),
+            check(5, Part::One, 100.into())
+        );
+        assert_eq!(Some(Warning::TooLow(100)), check(5, Part::One, 3.into()));
+        assert_eq!(Some(Warning::TooHigh(300)), check(5, Part::One, 301.into()));
+        assert_eq!(
+            Some(Warning::KnownWrong(Feedback::Wrong)),
+            check(5, Part::One, 200.into())
+        );
+
+        assert_eq!(None, check(5, Part::Two, 5.into()));
+        assert_eq!(
+            Some(Warning::NotCorrect(5.into())),
+            check(5, Part::Two, 6.into())
+        );
+
+        let text = |s: &str| Answer::Text(s.to_owned());
+        assert_eq!(
+            Some(Warning::KnownWrong(Feedback::Wrong)),
+            check(13, Part::Two, text("ABC"))
+        );
+        assert_eq!(None, check(13, Part::Two, text("ABD")));
+        assert_eq!(None, check(1, Part::One, 1.into()));
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let mut subs = submissions();
+        subs.record(
+            2021,
+            5,
+            Part::One,
+            Submission {
+                answer: 150.into(),
+                feedback: Feedback::Correct,
+            },
+        );
+        // re-recording an answer only updates its feedback
+        subs.record(
+            2021,
+            5,
+            Part::One,
+            Submission {
+                answer: 200.into(),
+                feedback: Feedback::TooHigh,
+            },
+        );
+        assert_eq!(4, subs.get(2021, 5, Part::One).len());
+
+        let path = std::env::temp_dir().join(format!("submissions-{}.toml", std::process::id()));
+        subs.save(&path).unwrap();
+        let loaded = Submissions::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(subs.get(2021, 5, Part::One), loaded.get(2021, 5, Part::One));
+        assert_eq!(
+            subs.get(2021, 13, Part::Two),
+            loaded.get(2021, 13, Part::Two)
+        );
+        assert_eq!(None, loaded.check(2021, 5, Part::One, &150.into()));
+    }
+}
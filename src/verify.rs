@@ -0,0 +1,177 @@
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::{answer::Answer, challenge::SolveInfo};
+
+/// Recorded answers for every day, loaded from `answers.toml` where they are keyed by year and
+/// then day, e.g. `[2021.05]`.
+#[derive(Debug)]
+pub struct Answers(BTreeMap<(usize, usize), DayAnswers>);
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DayAnswers {
+    pub real: Option<Expected>,
+    pub test: Option<Expected>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Expected {
+    pub part01: Answer,
+    pub part02: Answer,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let s = std::fs::read_to_string(path)
+            .with_context(|| format!("Reading file {}", path.display()))?;
+        Self::parse(&s).with_context(|| format!("Parsing answers file {}", path.display()))
+    }
+
+    fn parse(s: &str) -> Result<Self> {
+        Ok(Self(by_year_and_day(toml::from_str(s)?)?))
+    }
+
+    pub fn get(&self, year: usize, day: usize, test: bool) -> Option<&Expected> {
+        let answers = self.0.get(&(year, day))?;
+        if test {
+            answers.test.as_ref()
+        } else {
+            answers.real.as_ref()
+        }
+    }
+}
+
+/// Flattens tables keyed by year and then day, as used by the answers and submissions files.
+pub(crate) fn by_year_and_day<T>(
+    raw: BTreeMap<String, BTreeMap<String, T>>,
+) -> Result<BTreeMap<(usize, usize), T>> {
+    let mut flat = BTreeMap::new();
+    for (year, days) in raw {
+        let year: usize = year
+            .parse()
+            .with_context(|| format!("invalid year {:?}", year))?;
+        for (day, value) in days {
+            let day: usize = day
+                .parse()
+                .with_context(|| format!("invalid day {:?}", day))?;
+            flat.insert((year, day), value);
+        }
+    }
+    Ok(flat)
+}
+
+/// The result of checking a single day and input against its recorded answers.
+pub enum Verdict {
+    Pass,
+    Mismatch {
+        expected: Expected,
+        actual: Expected,
+    },
+    Missing,
+    Failed(anyhow::Error),
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&Expected>, result: Result<SolveInfo>) -> Self {
+        let solve = match result {
+            Ok(solve) => solve,
+            Err(e) => return Verdict::Failed(e),
+        };
+        let actual = Expected {
+            part01: solve.part01,
+            part02: solve.part02,
+        };
+        match expected {
+            None => Verdict::Missing,
+            Some(expected) if *expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.clone(),
+                actual,
+            },
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. } | Verdict::Failed(_))
+    }
+
+    pub fn print(&self, year: usize, day: usize, test: bool) {
+        let kind = if test { "test" } else { "real" };
+        let name = format!("{} Day {:02} ({})", year, day, kind);
+        match self {
+            Verdict::Pass => println!("{}: ok", name),
+            Verdict::Mismatch { expected, actual } => {
+                println!("{}: MISMATCH", name);
+                if expected.part01 != actual.part01 {
+                    println!(
+                        "  Part 1: expected {}, got {}",
+                        expected.part01, actual.part01
+                    );
+                }
+                if expected.part02 != actual.part02 {
+                    println!(
+                        "  Part 2: expected {}, got {}",
+                        expected.part02, actual.part02
+                    );
+                }
+            }
+            Verdict::Missing => println!("{}: no recorded answer", name),
+            Verdict::Failed(e) => println!("{}: FAILED {:#}", name, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Registry;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            r#"
+            [2021.01]
+            real = { part01 = 1, part02 = 2 }
+
+            [2021.12]
+            test = { part01 = 3, part02 = 4 }
+
+            [2015.01]
+            real = { part01 = 5, part02 = 6 }
+            "#,
+        )
+        .unwrap();
+
+        let expected = |part01: i64, part02: i64| Expected {
+            part01: part01.into(),
+            part02: part02.into(),
+        };
+        assert_eq!(Some(&expected(1, 2)), answers.get(2021, 1, false));
+        assert_eq!(None, answers.get(2021, 1, true));
+        assert_eq!(Some(&expected(3, 4)), answers.get(2021, 12, true));
+        assert_eq!(None, answers.get(2021, 2, false));
+        assert_eq!(Some(&expected(5, 6)), answers.get(2015, 1, false));
+    }
+
+    #[test]
+    fn test_every_day_recorded() {
+        let answers = Answers::parse(include_str!("../answers.toml")).unwrap();
+        for (year, day, _) in Registry::new().iter() {
+            assert!(
+                answers.get(year, day, false).is_some(),
+                "{} day {} real",
+                year,
+                day
+            );
+            assert!(
+                answers.get(year, day, true).is_some(),
+                "{} day {} test",
+                year,
+                day
+            );
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+    io::{self, BufRead, Write},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+
+use crate::{challenge::Solver, grid::Grid};
+
+/// Environment variable that turns colors off when set to anything, see https://no-color.org.
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+}
+
+impl Color {
+    fn ansi(self) -> &'static str {
+        match self {
+            Color::Red => "\x1b[0;31m",
+            Color::Green => "\x1b[0;32m",
+            Color::Yellow => "\x1b[0;33m",
+            Color::Blue => "\x1b[0;34m",
+        }
+    }
+}
+
+/// A single character of a frame. Colored cells carry a `plain` character that is drawn instead
+/// when colors are off, so that what they highlight is still visible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Color>,
+    pub plain: char,
+}
+
+impl Cell {
+    pub fn new(ch: char) -> Self {
+        Self {
+            ch,
+            color: None,
+            plain: ch,
+        }
+    }
+
+    pub fn colored(ch: char, color: Color, plain: char) -> Self {
+        Self {
+            ch,
+            color: Some(color),
+            plain,
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ')
+    }
+}
+
+/// One picture of a visualization along with a caption describing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, cells: Grid<Cell>) -> Self {
+        Self {
+            caption: caption.into(),
+            cells,
+        }
+    }
+
+    /// Draws the frame as text, one line per row after the caption.
+    pub fn render(&self, color: bool) -> String {
+        let mut out = String::with_capacity(self.cells.len() * 2);
+        out.push_str(&self.caption);
+        out.push('\n');
+        for row in self.cells.rows() {
+            for cell in row {
+                match cell.color {
+                    Some(c) if color => {
+                        out.push_str(c.ansi());
+                        out.push(cell.ch);
+                        out.push_str("\x1b[0m");
+                    }
+                    Some(_) => out.push(cell.plain),
+                    None => out.push(cell.ch),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Receives the frames of a visualization as they are produced.
+pub trait FrameSink {
+    fn frame(&mut self, frame: Frame) -> Result<()>;
+}
+
+/// A day that can show how it solves its puzzle, frame by frame.
+pub trait Visualize: Solver {
+    fn visualize(&self, input: &Self::Input<'_>, sink: &mut dyn FrameSink) -> Result<()>;
+}
+
+/// Object safe view of a `Visualize` that works directly on the raw puzzle input, like
+/// [`Challenge`](crate::challenge::Challenge) is for `Solver`.
+pub trait Visualization: Sync {
+    fn visualize(&self, input: &str, sink: &mut dyn FrameSink) -> Result<()>;
+}
+
+impl<V: Visualize + Sync> Visualization for V {
+    fn visualize(&self, input: &str, sink: &mut dyn FrameSink) -> Result<()> {
+        let input = self.parse(input)?;
+        Visualize::visualize(self, &input, sink)
+    }
+}
+
+/// Plays frames back in the terminal, replacing the previous frame each time.
+pub struct Terminal {
+    /// Time between frames, `None` waits for enter to be pressed instead.
+    delay: Option<Duration>,
+    color: bool,
+}
+
+impl Terminal {
+    /// Plays `fps` frames per second, 0 steps through the frames one enter press at a time.
+    pub fn new(fps: f64, color: bool) -> Self {
+        Self {
+            delay: (fps > 0.0).then(|| Duration::from_secs_f64(1.0 / fps)),
+            color,
+        }
+    }
+}
+
+impl FrameSink for Terminal {
+    fn frame(&mut self, frame: Frame) -> Result<()> {
+        let mut out = io::stdout().lock();
+        // clear the screen and move the cursor to the top left
+        write!(out, "\x1b[2J\x1b[H{}", frame.render(self.color))?;
+        out.flush()?;
+        drop(out);
+
+        match self.delay {
+            Some(delay) => thread::sleep(delay),
+            None => {
+                eprint!("enter for the next frame");
+                io::stdin().lock().read_line(&mut String::new())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether colors should be used, they are off when `NO_COLOR` is set.
+pub fn color_enabled() -> bool {
+    std::env::var_os(NO_COLOR_ENV).is_none_or(|v| v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let cells = Grid::from_rows(vec![
+            vec![Cell::new('1'), Cell::colored('2', Color::Red, '*')],
+            vec![Cell::new('3'), Cell::new('4')],
+        ])
+        .unwrap();
+        let frame = Frame::new("step 1", cells);
+        assert_eq!("step 1\n1*\n34\n", frame.render(false));
+        assert_eq!("step 1\n1\x1b[0;31m2\x1b[0m\n34\n", frame.render(true));
+    }
+}
@@ -0,0 +1,289 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::Write,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answer::Answer,
+    output::{self, Format, Record},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Polls a set of files for changes by their modification time.
+pub struct Watcher {
+    dir: PathBuf,
+    prefix: String,
+    files: Vec<PathBuf>,
+    seen: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Watcher {
+    /// Watches the `NN*.txt` inputs of `day` within `dir`, inputs that are created later are
+    /// picked up as well.
+    pub fn new(dir: impl Into<PathBuf>, day: usize) -> Self {
+        let mut watcher = Self {
+            dir: dir.into(),
+            prefix: format!("{:02}", day),
+            files: Vec::new(),
+            seen: BTreeMap::new(),
+        };
+        watcher.seen = watcher.scan();
+        watcher
+    }
+
+    /// Also watches a single file.
+    pub fn watch_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.files.push(path.into());
+        self.seen = self.scan();
+        self
+    }
+
+    /// Files that were created, modified or removed since the last call.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let current = self.scan();
+        let mut changed: Vec<PathBuf> = current
+            .iter()
+            .filter(|(path, mtime)| self.seen.get(*path) != Some(mtime))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.seen
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .cloned(),
+        );
+        self.seen = current;
+        changed
+    }
+
+    fn scan(&self) -> BTreeMap<PathBuf, SystemTime> {
+        let inputs = fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                name.starts_with(&self.prefix) && name.ends_with(".txt")
+            });
+        inputs
+            .chain(self.files.iter().cloned())
+            .filter_map(|path| Some((path.clone(), modified(&path)?)))
+            .collect()
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// The answers shown by the last solve. They are kept in a file so that a diff can still be shown
+/// after the binary was restarted, e.g. by cargo-watch.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Previous {
+    pub part01: Option<Answer>,
+    pub part02: Option<Answer>,
+}
+
+impl Previous {
+    fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string(self)?)
+            .with_context(|| format!("Writing file {}", path.display()))
+    }
+}
+
+/// Describes how the answers of `record` changed since `previous`, one line per part that was
+/// solved.
+pub fn diff(previous: &Previous, record: &Record) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (label, before, after) in [
+        ("Part 1", &previous.part01, &record.part01),
+        ("Part 2", &previous.part02, &record.part02),
+    ] {
+        let Some(after) = after else {
+            continue;
+        };
+        let line = match before {
+            None => format!("{}: new", label),
+            Some(before) if before == after => format!("{}: unchanged", label),
+            Some(before) if before.is_multiline() || after.is_multiline() => {
+                format!("{}: changed", label)
+            }
+            Some(before) => format!("{}: {} -> {}", label, before, after),
+        };
+        lines.push(line);
+    }
+    lines
+}
+
+/// Solves with `solve` every time a watched file changes, clearing the screen and printing the
+/// result with a diff against the previous answers. `state` is where the previous answers are
+/// kept. When the running binary is rebuilt it is restarted so that the new code is run. A solve
+/// that panics, e.g. on a half written input, is reported and watching carries on.
+pub fn run(mut watcher: Watcher, state: &Path, solve: impl Fn() -> Record) -> Result<()> {
+    let exe = std::env::current_exe().context("Locating the running binary")?;
+    let exe_modified = modified(&exe);
+
+    let mut previous = Previous::load(state);
+    let mut changed: Vec<PathBuf> = Vec::new();
+    loop {
+        let record = panic::catch_unwind(AssertUnwindSafe(&solve));
+
+        let mut out = std::io::stdout().lock();
+        // clear the screen and move the cursor to the top left
+        write!(out, "\x1b[2J\x1b[H")?;
+        match &record {
+            Ok(record) => {
+                output::write_records(&mut out, Format::Text, std::slice::from_ref(record))?
+            }
+            // the panic message was already printed by the panic hook
+            Err(_) => writeln!(out, "solve panicked")?,
+        }
+        if let Some(record) = record.ok().filter(|r| r.error.is_none()) {
+            writeln!(out)?;
+            for line in diff(&previous, &record) {
+                writeln!(out, "  {}", line)?;
+            }
+            // keep the answer of a part that wasn't run this time, e.g. with --part
+            previous = Previous {
+                part01: record.part01.or(previous.part01),
+                part02: record.part02.or(previous.part02),
+            };
+            previous.save(state)?;
+        }
+        writeln!(out)?;
+        for path in &changed {
+            writeln!(out, "changed: {}", path.display())?;
+        }
+        writeln!(out, "watching for changes, ctrl-c to stop")?;
+        drop(out);
+
+        changed = loop {
+            thread::sleep(POLL_INTERVAL);
+            if modified(&exe) != exe_modified {
+                // give the linker a moment to finish writing the binary
+                thread::sleep(POLL_INTERVAL);
+                return Err(restart(&exe));
+            }
+            let mut changed = watcher.changed();
+            if !changed.is_empty() {
+                // wait for writes to settle so that a file isn't read while it is being saved
+                thread::sleep(POLL_INTERVAL);
+                changed.extend(watcher.changed());
+                changed.sort();
+                changed.dedup();
+                break changed;
+            }
+        };
+    }
+}
+
+// replaces this process with the rebuilt binary, only returns if that failed
+#[cfg(unix)]
+fn restart(exe: &Path) -> anyhow::Error {
+    use std::os::unix::process::CommandExt;
+
+    let err = std::process::Command::new(exe)
+        .args(std::env::args_os().skip(1))
+        .exec();
+    anyhow::Error::new(err).context(format!("Restarting {}", exe.display()))
+}
+
+#[cfg(not(unix))]
+fn restart(exe: &Path) -> anyhow::Error {
+    match std::process::Command::new(exe)
+        .args(std::env::args_os().skip(1))
+        .status()
+    {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(e) => anyhow::Error::new(e).context(format!("Restarting {}", exe.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part01: Option<Answer>, part02: Option<Answer>) -> Record {
+        Record {
+            year: 2021,
+            day: 1,
+            challenge: "Sonar Sweep",
+            part01,
+            part02,
+            timings: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = Previous {
+            part01: Some(7.into()),
+            part02: Some(Answer::Render("#.\n".to_owned())),
+        };
+        assert_eq!(
+            vec!["Part 1: 7 -> 8", "Part 2: changed"],
+            diff(
+                &previous,
+                &record(Some(8.into()), Some(Answer::Render(".#\n".to_owned())))
+            )
+        );
+        assert_eq!(
+            vec!["Part 1: unchanged"],
+            diff(&previous, &record(Some(7.into()), None))
+        );
+        assert_eq!(
+            vec!["Part 1: new"],
+            diff(&Previous::default(), &record(Some(7.into()), None))
+        );
+    }
+
+    #[test]
+    fn test_watcher() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("05.txt"), "1").unwrap();
+        fs::write(dir.join("06.txt"), "1").unwrap();
+
+        let mut watcher = Watcher::new(&dir, 5);
+        assert!(watcher.changed().is_empty());
+
+        let touch = |name: &str, secs: u64| {
+            let file = fs::File::options()
+                .append(true)
+                .create(true)
+                .open(dir.join(name))
+                .unwrap();
+            file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+                .unwrap();
+        };
+        touch("05.txt", 1);
+        touch("06.txt", 1);
+        assert_eq!(vec![dir.join("05.txt")], watcher.changed());
+        assert!(watcher.changed().is_empty());
+
+        touch("05.test.txt", 2);
+        assert_eq!(vec![dir.join("05.test.txt")], watcher.changed());
+        fs::remove_file(dir.join("05.test.txt")).unwrap();
+        assert_eq!(vec![dir.join("05.test.txt")], watcher.changed());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    parse,
+};
+
+const DAY: usize = 1;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input<'a> = Vec<i64>;
+
+    fn name(&self) -> &'static str {
+        "Sonar Sweep"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part01(input).map(Answer::from)
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+// a slowly drifting depth that mostly increases like a real sonar sweep, with at least the three
+// readings of a window
+impl Generate for Day01 {
+    fn size_of(&self) -> &'static str {
+        "readings"
+    }
+
+    fn default_size(&self) -> usize {
+        2000
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut depth = rng.range(100, 200);
+        gen::join_line(
+            (0..size.max(3)).map(|_| {
+                depth = (depth + rng.range(-10, 20)).max(0);
+                depth
+            }),
+            "\n",
+        )
+    }
+}
+
+pub fn part02(input: &[i64]) -> anyhow::Result<i64> {
+    anyhow::ensure!(
+        input.len() >= 3,
+        "a window needs 3 readings, found {}",
+        input.len()
+    );
+    // neighbouring windows share two readings, so only the ones that differ are compared, which
+    // also keeps large readings from overflowing a sum
+    let inc = input.windows(4).filter(|w| w[3] > w[0]).count();
+    Ok(inc as i64)
+}
+
+pub fn part01(input: &[i64]) -> anyhow::Result<i64> {
+    let mut prev = &input[0];
+    let mut inc = 0;
+    for n in input.iter().skip(1) {
+        if n > prev {
+            inc += 1;
+        }
+        prev = n;
+    }
+    Ok(inc)
+}
+
+pub fn parse_input(input: &str) -> anyhow::Result<Vec<i64>> {
+    Ok(parse::numbers(DAY, input, input, "\n", "integer")?)
+}
@@ -0,0 +1,113 @@
+use anyhow::Context;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 2;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input<'a> = Vec<Command>;
+
+    fn name(&self) -> &'static str {
+        "Dive!"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part01(input).map(Answer::from)
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+pub enum Command {
+    Forward(i64),
+    Up(i64),
+    Down(i64),
+}
+
+impl Generate for Day02 {
+    fn size_of(&self) -> &'static str {
+        "commands"
+    }
+
+    fn default_size(&self) -> usize {
+        1000
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        gen::join_line(
+            (0..size).map(|_| {
+                let command = rng.choose(&["forward", "forward", "down", "up"]);
+                format!("{} {}", command, rng.range(1, 9))
+            }),
+            "\n",
+        )
+    }
+}
+
+pub fn part01(commands: &[Command]) -> anyhow::Result<i64> {
+    let mut hor = 0i64;
+    let mut depth = 0i64;
+    for command in commands {
+        match command {
+            Command::Forward(n) => hor = checked(hor.checked_add(*n))?,
+            Command::Up(n) => depth = checked(depth.checked_sub(*n))?,
+            Command::Down(n) => depth = checked(depth.checked_add(*n))?,
+        }
+    }
+    checked(hor.checked_mul(depth))
+}
+
+pub fn part02(commands: &[Command]) -> anyhow::Result<i64> {
+    let mut hor = 0i64;
+    let mut depth = 0i64;
+    let mut aim = 0i64;
+    for command in commands {
+        match command {
+            Command::Forward(n) => {
+                hor = checked(hor.checked_add(*n))?;
+                depth = checked(aim.checked_mul(*n).and_then(|d| depth.checked_add(d)))?;
+            }
+            Command::Up(n) => aim = checked(aim.checked_sub(*n))?,
+            Command::Down(n) => aim = checked(aim.checked_add(*n))?,
+        }
+    }
+    checked(hor.checked_mul(depth))
+}
+
+fn checked(n: Option<i64>) -> anyhow::Result<i64> {
+    n.context("the submarine goes further than an i64 can count")
+}
+
+pub fn parse_input(input: &str) -> anyhow::Result<Vec<Command>> {
+    let mut commands = Vec::new();
+    for line in input.lines() {
+        let (cmd, units) = parse::split_once(DAY, input, line, " ")?;
+        let units: i64 = parse::number(DAY, input, units, "integer")?;
+
+        let command = match cmd {
+            "forward" => Command::Forward(units),
+            "up" => Command::Up(units),
+            "down" => Command::Down(units),
+            _ => {
+                return Err(
+                    ParseError::new(DAY, input, cmd, "one of `forward`, `up`, `down`").into(),
+                )
+            }
+        };
+        commands.push(command);
+    }
+    Ok(commands)
+}
@@ -0,0 +1,193 @@
+use anyhow::Context;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 3;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input<'a> = (Vec<u16>, usize);
+
+    fn name(&self) -> &'static str {
+        "Binary Diagnostic"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part01(&input.0, input.1).map(Answer::from)
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(&input.0, input.1).map(Answer::from)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<u16>, usize), ParseError> {
+    let first = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new(DAY, input, parse::end_of(input), "binary number"))?;
+    // the report is kept as u16s
+    if !(1..=16).contains(&first.len()) {
+        return Err(ParseError::new(
+            DAY,
+            input,
+            first,
+            "binary number of 1 to 16 bits",
+        ));
+    }
+    let bit_width = first.len();
+    let report: Vec<u16> = input
+        .lines()
+        .map(|line| {
+            let binary = line.len() == bit_width && line.bytes().all(|b| b == b'0' || b == b'1');
+            match u16::from_str_radix(line, 2) {
+                Ok(n) if binary => Ok(n),
+                _ => Err(ParseError::new(
+                    DAY,
+                    input,
+                    line,
+                    format!("binary number of {} bits", bit_width),
+                )),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((report, bit_width))
+}
+
+// the ratings are only found if the numbers left after each bit criteria are never all the same in
+// the next bit, so the numbers are built by splitting them between a 0 and a 1 bit at every step
+impl Generate for Day03 {
+    fn size_of(&self) -> &'static str {
+        "binary numbers"
+    }
+
+    fn default_size(&self) -> usize {
+        1000
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.clamp(2, 1 << 16);
+        let width = (size.next_power_of_two().trailing_zeros() as usize + 1).clamp(5, 16);
+        let mut numbers = Vec::with_capacity(size);
+        split_numbers(rng, width, size, 0, &mut numbers);
+        rng.shuffle(&mut numbers);
+        gen::join_line(
+            numbers
+                .iter()
+                .map(|n| format!("{:0width$b}", n, width = width)),
+            "\n",
+        )
+    }
+}
+
+// `count` numbers starting with `prefix` followed by `width` more bits, 0 and 1 each start some of
+// them until only one is left
+fn split_numbers(rng: &mut Rng, width: usize, count: usize, prefix: u64, out: &mut Vec<u64>) {
+    if count == 1 {
+        out.push(prefix << width | rng.below(1 << width));
+        return;
+    }
+    let half = 1 << (width - 1);
+    let ones = rng.range(
+        count.saturating_sub(half).max(1) as i64,
+        (count - 1).min(half) as i64,
+    );
+    split_numbers(rng, width - 1, count - ones as usize, prefix << 1, out);
+    split_numbers(rng, width - 1, ones as usize, prefix << 1 | 1, out);
+}
+
+pub fn part01(report: &[u16], width: usize) -> anyhow::Result<i64> {
+    let mut gamma: u16 = 0;
+    let mut epsilon: u16 = 0;
+
+    for i in (0..width).rev() {
+        let mut ones = 0;
+        let mut zeros = 0;
+
+        for num in report {
+            let bit = num >> i & 1;
+            if bit == 1 {
+                ones += 1;
+            } else {
+                zeros += 1;
+            }
+        }
+
+        if ones > zeros {
+            gamma |= 1 << i;
+        } else {
+            epsilon |= 1 << i;
+        }
+    }
+
+    Ok(gamma as i64 * epsilon as i64)
+}
+
+pub fn part02(report: &[u16], width: usize) -> anyhow::Result<i64> {
+    let oxygen = oxygen_generator_rating(report, width).context("no oxygen generator rating")?;
+    let co2 = co2_scrubber_rating(report, width).context("no CO2 scrubber rating")?;
+    Ok(oxygen as i64 * co2 as i64)
+}
+
+// `None` when the bit criteria filter out every number, or leave more than one
+fn find_rating(report: &[u16], width: usize, cond: Cond) -> Option<u16> {
+    let mut report = report.to_owned();
+    for i in (0..width).rev() {
+        let mut ones = 0;
+        let mut zeros = 0;
+
+        for num in report.iter() {
+            let bit = num >> i & 1;
+            if bit == 1 {
+                ones += 1;
+            } else {
+                zeros += 1;
+            }
+        }
+
+        match cond {
+            Cond::MostCommon => {
+                if ones >= zeros {
+                    report.retain(|num| num >> i & 1 == 1);
+                } else {
+                    report.retain(|num| num >> i & 1 == 0);
+                }
+            }
+            Cond::LeastCommon => {
+                if ones < zeros {
+                    report.retain(|num| num >> i & 1 == 1);
+                } else {
+                    report.retain(|num| num >> i & 1 == 0);
+                }
+            }
+        }
+
+        if report.len() == 1 {
+            return Some(report[0]);
+        }
+    }
+    None
+}
+
+enum Cond {
+    MostCommon,
+    LeastCommon,
+}
+
+fn oxygen_generator_rating(report: &[u16], width: usize) -> Option<u16> {
+    find_rating(report, width, Cond::MostCommon)
+}
+
+fn co2_scrubber_rating(report: &[u16], width: usize) -> Option<u16> {
+    find_rating(report, width, Cond::LeastCommon)
+}
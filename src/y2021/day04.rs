@@ -0,0 +1,246 @@
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    parse::{self, ParseError},
+};
+
+use std::str::FromStr;
+
+const DAY: usize = 4;
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input<'a> = Game;
+
+    fn name(&self) -> &'static str {
+        "Giant Squid"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(input.parse()?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part01(input).map(Answer::from)
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+// every number is called so every board wins eventually, and only one board wins last as the last
+// winner couldn't be told apart otherwise
+impl Generate for Day04 {
+    fn size_of(&self) -> &'static str {
+        "boards"
+    }
+
+    fn default_size(&self) -> usize {
+        100
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut calls: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut calls);
+        let mut turns = [0; 100];
+        for (turn, &n) in calls.iter().enumerate() {
+            turns[n as usize] = turn;
+        }
+
+        let mut random_board = || {
+            let mut numbers: Vec<u32> = (0..100).collect();
+            rng.shuffle(&mut numbers);
+            numbers.truncate(25);
+            numbers
+        };
+        let mut boards: Vec<Vec<u32>> = (0..size.max(1)).map(|_| random_board()).collect();
+        loop {
+            let wins: Vec<usize> = boards.iter().map(|b| winning_turn(b, &turns)).collect();
+            let last = *wins.iter().max().unwrap();
+            match wins.iter().rposition(|&w| w == last) {
+                Some(i) if wins.iter().filter(|&&w| w == last).count() > 1 => {
+                    boards[i] = random_board()
+                }
+                _ => break,
+            }
+        }
+
+        let mut out = gen::join_line(&calls, ",");
+        for board in boards {
+            out.push('\n');
+            for row in board.chunks(5) {
+                out.push_str(&gen::join_line(row.iter().map(|n| format!("{:2}", n)), " "));
+            }
+        }
+        out
+    }
+}
+
+// the turn on which a board of 5x5 numbers wins, given the turn each number is called on
+fn winning_turn(board: &[u32], turns: &[usize; 100]) -> usize {
+    let turn = |i: usize| turns[board[i] as usize];
+    let rows = (0..5).map(|r| (0..5).map(|c| turn(r * 5 + c)).max().unwrap());
+    let columns = (0..5).map(|c| (0..5).map(|r| turn(r * 5 + c)).max().unwrap());
+    rows.chain(columns).min().unwrap()
+}
+
+pub fn part01(game: &Game) -> anyhow::Result<i64> {
+    let mut game = game.clone();
+    for call in &game.calls {
+        for board in &mut game.boards {
+            board.mark(*call);
+            if board.winner() {
+                return Ok(board.score(*call));
+            }
+        }
+    }
+    anyhow::bail!("no board wins")
+}
+
+pub fn part02(game: &Game) -> anyhow::Result<i64> {
+    let mut game = game.clone();
+
+    for call in &game.calls {
+        for board in &mut game.boards {
+            board.mark(*call);
+        }
+        if game.boards.len() == 1 {
+            // even though it's the last board, we need the final score after this board has won
+            if game.boards[0].winner() {
+                return Ok(game.boards[0].score(*call));
+            }
+        } else {
+            game.boards.retain(|b| !b.winner());
+            anyhow::ensure!(
+                !game.boards.is_empty(),
+                "several boards win last, on {}",
+                call
+            );
+        }
+    }
+    anyhow::bail!("{} board(s) never win", game.boards.len())
+}
+
+#[derive(Clone)]
+pub struct Game {
+    calls: Vec<u16>,
+    boards: Vec<Board>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chunks: Vec<_> = parse::sections(s).collect();
+        let calls = parse::numbers(DAY, s, chunks[0], ",", "called number")?;
+        let boards = chunks[1..]
+            .iter()
+            .map(|chunk| chunk.parse().map_err(|e: ParseError| e.within(s, chunk)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { calls, boards })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Cell {
+    num: u16,
+    called: bool,
+}
+
+impl FromStr for Cell {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Cell {
+            num: parse::number(DAY, s, s, "board number")?,
+            called: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Board(Vec<Cell>);
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cells: Vec<_> = s
+            .split_whitespace() // splits on both " " and \n
+            .map(|n| n.parse::<Cell>().map_err(|e| e.within(s, n)))
+            .collect::<Result<_, _>>()?;
+        if cells.len() != 25 {
+            return Err(ParseError::new(
+                DAY,
+                s,
+                parse::end_of(s.trim_end()),
+                format!("25 board numbers, got {}", cells.len()),
+            ));
+        }
+        Ok(Board(cells))
+    }
+}
+
+impl Board {
+    fn mark(&mut self, num: u16) {
+        for cell in self.0.iter_mut() {
+            if cell.num == num {
+                cell.called = true;
+            }
+        }
+    }
+
+    fn winner(&self) -> bool {
+        self.horz_win() || self.vert_win()
+    }
+
+    fn horz_win(&self) -> bool {
+        // for each row #
+        //   row * 5: determines starting position in full array for this row
+        //   .take(5): rows are 5 cells long
+        //   .all(...): check if all cells are marked
+        (0..5).any(|row| ((row * 5)..).take(5).all(|idx| self.0[idx].called))
+    }
+
+    fn vert_win(&self) -> bool {
+        // for each col #
+        //   step_by(5): produce iterator that skips through final list 5 at a time (0, 5, 10, etc.)
+        //   .take(5): cols are 5 cells long
+        //   .all(...): check if all cells are marked
+        (0..5).any(|col| (col..).step_by(5).take(5).all(|idx| self.0[idx].called))
+    }
+
+    fn score(&self, winning_number: u16) -> i64 {
+        self.sum_unmarked() * winning_number as i64
+    }
+
+    fn sum_unmarked(&self) -> i64 {
+        self.0
+            .iter()
+            .filter(|cell| !cell.called)
+            .map(|cell| cell.num as i64)
+            .sum()
+    }
+}
+
+// experiment packing the mark bit into the u8 directly.
+// -- individual testing works but didn't work with existing algo for some reason
+// struct C(u8);
+
+// impl C {
+//     fn value(&self) -> u8 {
+//         self.0 & 0b01111111
+//     }
+
+//     fn mark(&mut self) {
+//         self.0 |= 0b10000000
+//     }
+
+//     fn marked(&self) -> bool {
+//         self.0 & 0b10000000 == 1
+//     }
+// }
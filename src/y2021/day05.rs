@@ -0,0 +1,210 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    grid::Grid,
+    parse::{self, ParseError},
+    visualize::{Cell, Color, Frame, FrameSink, Visualize},
+};
+
+const DAY: usize = 5;
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input<'a> = Vec<Line>;
+
+    fn name(&self) -> &'static str {
+        "Hydrothermal Venture"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part02(input).into())
+    }
+}
+
+// covers the lines of part 2 one at a time, the grid is cropped to the area the lines cover
+impl Visualize for Day05 {
+    fn visualize(&self, lines: &Self::Input<'_>, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let (width, height) = lines
+            .iter()
+            .flat_map(|l| [&l.0, &l.1])
+            .fold((0, 0), |(w, h), p| (w.max(p.0 + 1), h.max(p.1 + 1)));
+        let mut grid = Grid::new(width, height, 0);
+
+        for (i, line) in lines.iter().enumerate() {
+            cover(&mut grid, line);
+
+            let mut cells = grid.map(|&n| match n {
+                0 => Cell::new('.'),
+                n => Cell::new(char::from_digit(n.min(9), 10).unwrap()),
+            });
+            for point in line.points() {
+                cells[point] = Cell::colored(cells[point].ch, Color::Green, '+');
+            }
+            let caption = format!(
+                "line {}/{}: {},{} -> {},{}, {} overlapping",
+                i + 1,
+                lines.len(),
+                line.0 .0,
+                line.0 .1,
+                line.1 .0,
+                line.1 .1,
+                overlapping(&grid)
+            );
+            sink.frame(Frame::new(caption, cells))?;
+        }
+        Ok(())
+    }
+}
+
+// horizontal, vertical and diagonal lines that stay within the grid
+impl Generate for Day05 {
+    fn size_of(&self) -> &'static str {
+        "lines"
+    }
+
+    fn default_size(&self) -> usize {
+        500
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let max = SIZE as i64 - 1;
+        gen::join_line(
+            (0..size.max(1)).map(|_| {
+                let (x1, y1) = (rng.range(0, max), rng.range(0, max));
+                let (dx, dy) = *rng.choose(&[(1, 0), (0, 1), (1, 1), (1, -1)]);
+                // as long as possible in the chosen direction without leaving the grid
+                let limit = |p: i64, d: i64| match d {
+                    1 => max - p,
+                    -1 => p,
+                    _ => max,
+                };
+                let len = rng.range(0, limit(x1, dx).min(limit(y1, dy)).min(300));
+                let (x2, y2) = (x1 + dx * len, y1 + dy * len);
+                if rng.chance(1, 2) {
+                    format!("{},{} -> {},{}", x1, y1, x2, y2)
+                } else {
+                    format!("{},{} -> {},{}", x2, y2, x1, y1)
+                }
+            }),
+            "\n",
+        )
+    }
+}
+
+pub fn part01(lines: &[Line]) -> i64 {
+    let mut grid = Grid::new(SIZE, SIZE, 0);
+    lines
+        .iter()
+        .filter(|l| !l.is_diag())
+        .for_each(|l| cover(&mut grid, l));
+    overlapping(&grid)
+}
+
+pub fn part02(lines: &[Line]) -> i64 {
+    let mut grid = Grid::new(SIZE, SIZE, 0);
+    lines.iter().for_each(|l| cover(&mut grid, l));
+    overlapping(&grid)
+}
+
+const SIZE: usize = 1000;
+
+// marks every point on the line
+fn cover(grid: &mut Grid<u32>, line: &Line) {
+    for point in line.points() {
+        grid[point] += 1;
+    }
+}
+
+fn overlapping(grid: &Grid<u32>) -> i64 {
+    grid.values().filter(|&&n| n > 1).count() as i64
+}
+
+#[derive(Debug)]
+pub struct Line(pub Point, pub Point);
+
+impl Line {
+    // every point on the line, which is horizontal, vertical or at 45 degrees
+    fn points(&self) -> Vec<(usize, usize)> {
+        let mut pos = (self.0 .0, self.0 .1);
+        let end = (self.1 .0, self.1 .1);
+
+        let mut points = vec![pos];
+        while pos != end {
+            // adjust the current position towards the ending pos
+            match pos.0.cmp(&end.0) {
+                Ordering::Less => pos.0 += 1,
+                Ordering::Greater => pos.0 -= 1,
+                Ordering::Equal => {}
+            }
+            match pos.1.cmp(&end.1) {
+                Ordering::Less => pos.1 += 1,
+                Ordering::Greater => pos.1 -= 1,
+                Ordering::Equal => {}
+            }
+            points.push(pos);
+        }
+        points
+    }
+
+    fn is_horz(&self) -> bool {
+        self.0 .1 == self.1 .1
+    }
+
+    fn is_vert(&self) -> bool {
+        self.0 .0 == self.1 .0
+    }
+
+    fn is_diag(&self) -> bool {
+        !self.is_horz() && !self.is_vert()
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = parse::rule(DAY, s, s)?;
+        let start: Point = start.parse().map_err(|e: ParseError| e.within(s, start))?;
+        let end: Point = end.parse().map_err(|e: ParseError| e.within(s, end))?;
+        Ok(Line(start, end))
+    }
+}
+
+#[derive(Debug)]
+pub struct Point(pub usize, pub usize);
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse::split_once(DAY, s, s, ",")?;
+        let coord = |c: &str| -> Result<usize, ParseError> {
+            match c.parse() {
+                Ok(n) if n < SIZE => Ok(n),
+                _ => Err(ParseError::new(
+                    DAY,
+                    s,
+                    c,
+                    format!("coordinate below {}", SIZE),
+                )),
+            }
+        };
+        Ok(Point(coord(x)?, coord(y)?))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    parse::lines(input)
+}
@@ -0,0 +1,113 @@
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 6;
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input<'a> = Vec<i64>;
+
+    fn name(&self) -> &'static str {
+        "Lanternfish"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part02(input).into())
+    }
+}
+
+impl Generate for Day06 {
+    fn size_of(&self) -> &'static str {
+        "fish"
+    }
+
+    fn default_size(&self) -> usize {
+        300
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        gen::join_line((0..size.max(1)).map(|_| rng.range(1, 5)), ",")
+    }
+}
+
+pub fn part01(initial_pop: &[i64]) -> i64 {
+    simulate(initial_pop, 80)
+}
+
+pub fn part02(initial_pop: &[i64]) -> i64 {
+    simulate(initial_pop, 256)
+}
+
+fn simulate(inital_pop: &[i64], days: i64) -> i64 {
+    // pop represents the population of lanternfish by grouping lanternfish into groups by age
+    let mut pop = [0; 9];
+
+    // add initial population to aged population array
+    inital_pop.iter().for_each(|i| pop[*i as usize] += 1);
+
+    // simulate days
+    (0..days).for_each(|_| {
+        pop.rotate_left(1);
+        pop[6] += pop[8];
+    });
+
+    pop.iter().sum()
+}
+
+pub fn parse_input(input: &str) -> anyhow::Result<Vec<i64>> {
+    let ages = parse::list_with(input, ",", |s| {
+        match parse::number(DAY, input, s, "timer between 0 and 8")? {
+            n @ 0..=8 => Ok(n),
+            _ => Err(ParseError::new(DAY, input, s, "timer between 0 and 8")),
+        }
+    })?;
+    Ok(ages)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // simulates every fish on its own, only feasible for a few days
+    fn brute_force(initial_pop: &[i64], days: i64) -> i64 {
+        let mut fish = initial_pop.to_vec();
+        for _ in 0..days {
+            let mut born = 0;
+            for timer in fish.iter_mut() {
+                if *timer == 0 {
+                    *timer = 6;
+                    born += 1;
+                } else {
+                    *timer -= 1;
+                }
+            }
+            fish.extend(std::iter::repeat_n(8, born));
+        }
+        fish.len() as i64
+    }
+
+    proptest! {
+        #[test]
+        fn test_simulate_matches_brute_force(
+            initial_pop in prop::collection::vec(0..=8i64, 1..10),
+            days in 0..60i64,
+        ) {
+            prop_assert_eq!(brute_force(&initial_pop, days), simulate(&initial_pop, days));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::Context;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 7;
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input<'a> = Vec<i64>;
+
+    fn name(&self) -> &'static str {
+        "The Treachery of Whales"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part01(input).map(Answer::from)
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+impl Generate for Day07 {
+    fn size_of(&self) -> &'static str {
+        "crabs"
+    }
+
+    fn default_size(&self) -> usize {
+        1000
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        gen::join_line((0..size.max(1)).map(|_| rng.range(0, 1999)), ",")
+    }
+}
+
+pub fn part01(positions: &[i64]) -> anyhow::Result<i64> {
+    solve(positions, |start, end| end.checked_sub(start))
+}
+
+pub fn part02(positions: &[i64]) -> anyhow::Result<i64> {
+    // adjusts the range to begin at 1 then calculates the sum of integers
+    solve(positions, |start, end| {
+        let a = 1;
+        let i = end.checked_sub(start)?; // adjustment for 1-based range
+        let n = i;
+        n.checked_mul(a + i).map(|sum| sum / 2)
+    })
+}
+
+fn solve<F>(positions: &[i64], fuel_calc: F) -> anyhow::Result<i64>
+where
+    F: Fn(i64, i64) -> Option<i64>,
+{
+    let min_pos = *positions.iter().min().unwrap();
+    let max_pos = *positions.iter().max().unwrap();
+    // positions whose fuel does not fit an i64 are never the cheapest one that does
+    (min_pos..=max_pos)
+        .filter_map(|desired_pos| {
+            positions.iter().try_fold(0i64, |fuel, crab_pos| {
+                let start = desired_pos.min(*crab_pos);
+                let end = desired_pos.max(*crab_pos);
+                fuel.checked_add(fuel_calc(start, end)?)
+            })
+        })
+        .min()
+        .context("the fuel to align the crabs does not fit an i64")
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse::numbers(DAY, input, input, ",", "crab position")
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // tries every position the crabs could align on, costing every step one at a time
+    fn brute_force(positions: &[i64], step_cost: impl Fn(i64) -> i64) -> i64 {
+        let max_pos = *positions.iter().max().unwrap();
+        (0..=max_pos)
+            .map(|target| {
+                positions
+                    .iter()
+                    .map(|&pos| (1..=(pos - target).abs()).map(&step_cost).sum::<i64>())
+                    .sum()
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_align_on_farthest_crab() {
+        // the cheapest position is the largest one, which the scan has to include
+        assert_eq!(5, part01(&[0, 5, 5, 5, 5, 5]).unwrap());
+        assert_eq!(15, part02(&[0, 5, 5, 5, 5, 5]).unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn test_solve_matches_brute_force(positions in prop::collection::vec(0..50i64, 1..20)) {
+            prop_assert_eq!(brute_force(&positions, |_| 1), part01(&positions).unwrap());
+            prop_assert_eq!(brute_force(&positions, |step| step), part02(&positions).unwrap());
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::{fmt::Write, str::FromStr};
+
+use anyhow::Context;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 8;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input<'a> = Vec<Display>;
+
+    fn name(&self) -> &'static str {
+        "Seven Segment Search"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+// every entry wires the segments up differently and shuffles the patterns
+impl Generate for Day08 {
+    fn size_of(&self) -> &'static str {
+        "entries"
+    }
+
+    fn default_size(&self) -> usize {
+        200
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // segments of each digit, a is the top and g the bottom
+        const DIGITS: [&str; 10] = [
+            "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+        ];
+
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            let digits: Vec<usize> = (0..4).map(|_| rng.index(10)).collect();
+            let mut order: Vec<usize> = (0..10).collect();
+            rng.shuffle(&mut order);
+            let mut wires: Vec<char> = ('a'..='g').collect();
+            rng.shuffle(&mut wires);
+            let mut pattern = |digit: usize| {
+                let mut segments: Vec<char> = DIGITS[digit]
+                    .chars()
+                    .map(|c| wires[c as usize - 'a' as usize])
+                    .collect();
+                rng.shuffle(&mut segments);
+                segments.into_iter().collect::<String>()
+            };
+
+            let patterns: Vec<String> = order.into_iter().map(&mut pattern).collect();
+            let output: Vec<String> = digits.into_iter().map(&mut pattern).collect();
+            writeln!(out, "{} | {}", patterns.join(" "), output.join(" ")).unwrap();
+        }
+        out
+    }
+}
+
+pub fn part01(measurements: &[Display]) -> i64 {
+    measurements
+        .iter()
+        .flat_map(|m| &m.1)
+        .filter(|digit| matches!(digit.len(), 2 | 3 | 4 | 7)) // numbers: 1 | 7 | 4 | 8
+        .count() as i64
+}
+
+pub fn part02(displays: &[Display]) -> anyhow::Result<i64> {
+    displays
+        .iter()
+        .enumerate()
+        .try_fold(0, |sum, (i, display)| {
+            let value = display.decode().with_context(|| {
+                format!(
+                    "display {} doesn't show the ten digits with outputs among them",
+                    i + 1
+                )
+            })?;
+            Ok(sum + value)
+        })
+}
+
+/// The ten unique signal patterns followed by the four output digits of a single display.
+pub struct Display(pub Vec<String>, pub Vec<String>);
+
+impl Display {
+    // `None` if the patterns aren't the ten digits of some wiring or an output isn't one of them
+    fn decode(&self) -> Option<i64> {
+        let char_signals: Vec<Vec<char>> = self.0.iter().map(|s| s.chars().collect()).collect();
+        let one = char_signals.iter().find(|cs| cs.len() == 2)?;
+        let four = char_signals.iter().find(|cs| cs.len() == 4)?;
+        let seven = char_signals.iter().find(|cs| cs.len() == 3)?;
+        // remove known numbers from list
+        let char_signals: Vec<Vec<char>> = char_signals
+            .clone()
+            .into_iter()
+            .filter(|digit| !matches!(digit.len(), 2 | 3 | 4 | 7))
+            .collect();
+
+        // (top) can be determined by subtracting 1 from 7
+        let top = seven.iter().find(|c| !one.contains(c))?;
+
+        // (bot) subtract 7 and 4 from 9 (has all from 7 and 4)
+        let nine: Vec<char> = char_signals
+            .clone()
+            .into_iter()
+            .filter(|cs| cs.len() == 6)
+            .find(|cs| {
+                seven.iter().all(|c| cs.contains(c)) && four.iter().all(|c| cs.contains(c))
+            })?;
+        let bot = nine
+            .iter()
+            .find(|c| !(seven.contains(c) || four.contains(c)))?;
+
+        // (mid) if you filter 9 from the list. 0 is the only number left that contains all
+        // sections from 1. you can determine mid by finding missing section
+        let zero = char_signals
+            .clone()
+            .into_iter()
+            .filter(|cs| cs.len() == 6)
+            .filter(|cs| *cs != nine)
+            .find(|cs| one.iter().all(|c| cs.contains(c)))?;
+        let mid = ['a', 'b', 'c', 'd', 'e', 'f', 'g']
+            .iter()
+            .find(|c| !zero.contains(c))?;
+
+        // (top-left) subtract top-right, mid, and bot-right from 4
+        let top_left = four.iter().find(|c| !(one.contains(c) || mid == *c))?;
+
+        // (bot-left) subtract 7 from 0, then subtract top-left, bot
+        let bot_left = zero
+            .iter()
+            .find(|c| !(seven.contains(c) || *c == top_left || *c == bot))?;
+
+        // (top-right) digit 2 has all of the following (top, mid, bot, bot-left, one digit from
+        //   1). the digit that is shared with 1 is the top right
+        let two = char_signals
+            .into_iter()
+            .filter(|cs| cs.len() == 5)
+            .find(|cs| {
+                cs.contains(top)
+                    && cs.contains(mid)
+                    && cs.contains(bot)
+                    && cs.contains(bot_left)
+                    && (cs.contains(&one[0]) || cs.contains(&one[1]))
+            })?;
+        let top_right = two.iter().find(|c| **c == one[0] || **c == one[1])?;
+
+        // (bot-right) find remaining section
+        let bot_right = ['a', 'b', 'c', 'd', 'e', 'f', 'g'].iter().find(|c| {
+            !(*c == top
+                || *c == bot
+                || *c == top_left
+                || *c == top_right
+                || *c == mid
+                || *c == bot_left)
+        })?;
+
+        // [top, top_left, top_right, mid, bot_left, bot_right, bot],
+        let masks = [
+            (
+                0,
+                [
+                    *top, *top_left, *top_right, ' ', *bot_left, *bot_right, *bot,
+                ],
+            ),
+            (1, [' ', ' ', *top_right, ' ', ' ', *bot_right, ' ']),
+            (2, [*top, ' ', *top_right, *mid, *bot_left, ' ', *bot]),
+            (3, [*top, ' ', *top_right, *mid, ' ', *bot_right, *bot]),
+            (4, [' ', *top_left, *top_right, *mid, ' ', *bot_right, ' ']),
+            (5, [*top, *top_left, ' ', *mid, ' ', *bot_right, *bot]),
+            (6, [*top, *top_left, ' ', *mid, *bot_left, *bot_right, *bot]),
+            (7, [*top, ' ', *top_right, ' ', ' ', *bot_right, ' ']),
+            (
+                8,
+                [
+                    *top, *top_left, *top_right, *mid, *bot_left, *bot_right, *bot,
+                ],
+            ),
+            (
+                9,
+                [*top, *top_left, *top_right, *mid, ' ', *bot_right, *bot],
+            ),
+        ];
+
+        let mut value = 0;
+        for mask in &self.1 {
+            let mut sections = [' '; 7];
+            for c in mask.chars() {
+                match c {
+                    c if c == *top => sections[0] = c,
+                    c if c == *top_left => sections[1] = c,
+                    c if c == *top_right => sections[2] = c,
+                    c if c == *mid => sections[3] = c,
+                    c if c == *bot_left => sections[4] = c,
+                    c if c == *bot_right => sections[5] = c,
+                    c if c == *bot => sections[6] = c,
+                    _ => return None,
+                }
+            }
+
+            let (digit, _) = masks.iter().find(|(_, mask)| sections == *mask)?;
+            value = value * 10 + digit;
+        }
+        Some(value)
+    }
+}
+
+impl FromStr for Display {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sigpat, out) = parse::split_once(DAY, s, s.trim(), " | ")?;
+        let signal_patterns: Vec<&str> = segments(s, sigpat, 10, "10 signal patterns")?;
+        let output_value: Vec<&str> = segments(s, out, 4, "4 output digits")?;
+
+        let signal_patterns = signal_patterns
+            .into_iter()
+            .map(|s| {
+                let mut chars: Vec<char> = s.chars().collect();
+                chars.sort_by(|a, b| b.cmp(a));
+                String::from_iter(chars)
+            })
+            .collect();
+        let output_value = output_value.into_iter().map(|s| s.to_owned()).collect();
+        Ok(Display(signal_patterns, output_value))
+    }
+}
+
+// splits space separated segment patterns, ensuring there are `count` of them and that they only
+// contain the segments a-g
+fn segments<'a>(
+    src: &str,
+    s: &'a str,
+    count: usize,
+    expected: &str,
+) -> Result<Vec<&'a str>, ParseError> {
+    let patterns: Vec<&str> = s.split(' ').collect();
+    if patterns.len() != count {
+        return Err(ParseError::new(DAY, src, s, expected));
+    }
+    for pattern in &patterns {
+        if pattern.is_empty() || !pattern.chars().all(|c| ('a'..='g').contains(&c)) {
+            return Err(ParseError::new(DAY, src, pattern, "segments a-g"));
+        }
+    }
+    Ok(patterns)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Display>, ParseError> {
+    input
+        .lines()
+        .map(|line| line.parse().map_err(|e: ParseError| e.within(input, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn decode_test() {
+        let s =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+        let m: Display = s.parse().unwrap();
+        assert_eq!(Some(5353), m.decode());
+    }
+
+    #[test]
+    fn test_part02_undecodable() {
+        let s = "abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg abcdefg | a b c d";
+        let displays = parse_input(s).unwrap();
+        assert!(part02(&displays).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    grid::{Grid, Point},
+    parse::{self, ParseError},
+    search,
+    visualize::{Cell, Color, Frame, FrameSink, Visualize},
+};
+
+const DAY: usize = 9;
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input<'a> = Grid<u32>;
+
+    fn name(&self) -> &'static str {
+        "Smoke Basin"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part02(input).into())
+    }
+}
+
+// fills the basins one at a time, then picks out the three largest
+impl Visualize for Day09 {
+    fn visualize(&self, grid: &Grid<u32>, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let low_points = find_low_points(grid);
+        let mut cells = grid.map(|&height| Cell::new(char::from_digit(height, 10).unwrap_or('?')));
+        for &point in &low_points {
+            cells[point] = Cell::colored(cells[point].ch, Color::Red, 'v');
+        }
+
+        let mut basins: Vec<Vec<Point>> = Vec::new();
+        for (i, &low) in low_points.iter().enumerate() {
+            // the previous basin is no longer the current one
+            for &point in basins.last().into_iter().flatten() {
+                cells[point].color = Some(Color::Blue);
+            }
+            let basin = basin(grid, low);
+            for &point in &basin {
+                if point != low {
+                    cells[point] = Cell::colored(cells[point].ch, Color::Green, '~');
+                }
+            }
+            let caption = format!("basin {}/{}, size {}", i + 1, low_points.len(), basin.len());
+            sink.frame(Frame::new(caption, cells.clone()))?;
+            basins.push(basin);
+        }
+
+        basins.sort_unstable_by_key(|basin| std::cmp::Reverse(basin.len()));
+        for &point in basins.iter().take(3).flatten() {
+            cells[point] = Cell::colored(cells[point].ch, Color::Yellow, '#');
+        }
+        let caption = format!("three largest basins, product {}", part02(grid));
+        sink.frame(Frame::new(caption, cells))
+    }
+}
+
+// every basin has a single low point, like the puzzle promises: the grid is split between random
+// low points, walled off from each other with 9s and rises with the distance to its low point
+impl Generate for Day09 {
+    fn size_of(&self) -> &'static str {
+        "rows and columns"
+    }
+
+    fn default_size(&self) -> usize {
+        100
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+        let low_points: Vec<Point> = (0..(size * size / 50).max(1))
+            .map(|_| (rng.index(size), rng.index(size)))
+            .collect();
+
+        // each point belongs to the closest low point
+        let mut owners = Grid::new(size, size, None);
+        let mut queue = VecDeque::new();
+        for (i, &p) in low_points.iter().enumerate() {
+            if owners[p].is_none() {
+                owners[p] = Some(i);
+                queue.push_back(p);
+            }
+        }
+        while let Some(p) = queue.pop_front() {
+            for n in owners.neighbors4(p).collect::<Vec<_>>() {
+                if owners[n].is_none() {
+                    owners[n] = owners[p];
+                    queue.push_back(n);
+                }
+            }
+        }
+        let owners = owners.map(|owner| owner.unwrap());
+        let wall = |p: Point| owners.neighbors4(p).any(|n| owners[n] > owners[p]);
+
+        let mut heights = Grid::new(size, size, 9);
+        for (i, &low) in low_points.iter().enumerate() {
+            if owners[low] != i || wall(low) {
+                continue;
+            }
+            let neighbors = |p| {
+                owners
+                    .neighbors4(p)
+                    .filter(|&n| owners[n] == i && !wall(n))
+                    .map(|n| (n, 1))
+            };
+            for (p, steps) in search::reachable(&neighbors, low) {
+                heights[p] = steps.min(8);
+            }
+        }
+        heights.to_string()
+    }
+}
+
+pub fn part01(grid: &Grid<u32>) -> i64 {
+    let low_points = find_low_points(grid);
+    // calc risk level
+    low_points.iter().map(|&point| grid[point] + 1).sum::<u32>() as i64
+}
+
+pub fn part02(grid: &Grid<u32>) -> i64 {
+    let low_points = find_low_points(grid);
+
+    let mut basin_sizes: Vec<i64> = low_points.iter().map(|p| basin_size(grid, *p)).collect();
+    basin_sizes.sort_unstable();
+    basin_sizes.iter().rev().take(3).product()
+}
+
+fn basin_size(grid: &Grid<u32>, p: Point) -> i64 {
+    basin(grid, p).len() as i64
+}
+
+// basins are bounded by 9s, everything reachable from the low point without crossing one
+fn basin(grid: &Grid<u32>, p: Point) -> Vec<Point> {
+    let neighbors = |point| {
+        grid.neighbors4(point)
+            .filter(|&n| grid[n] != 9)
+            .map(|n| (n, 1))
+    };
+    search::reachable(&neighbors, p).into_keys().collect()
+}
+
+// points that are lower than all of their neighbors
+fn find_low_points(grid: &Grid<u32>) -> Vec<Point> {
+    grid.points()
+        .filter(|&point| grid.neighbors4(point).all(|n| grid[point] < grid[n]))
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    parse::digit_grid(DAY, input)
+}
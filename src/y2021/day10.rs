@@ -0,0 +1,181 @@
+use anyhow::Context;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    parse::ParseError,
+};
+
+const DAY: usize = 10;
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn name(&self) -> &'static str {
+        "Syntax Scoring"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+// a mix of corrupted and incomplete lines, with an odd number of incomplete ones so that there is
+// a middle score, and few enough unclosed chunks for the score to fit
+impl Generate for Day10 {
+    fn size_of(&self) -> &'static str {
+        "lines"
+    }
+
+    fn default_size(&self) -> usize {
+        100
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        const OPEN: [char; 4] = ['(', '[', '{', '<'];
+        let size = size.max(1);
+        let incomplete_lines = (size / 2) | 1;
+
+        let mut out = String::new();
+        for i in 0..size {
+            let length = rng.range(20, 110) as usize;
+            let mut stack = Vec::new();
+            let mut line = String::new();
+            while line.len() < length {
+                if stack.is_empty() || (stack.len() < 20 && rng.chance(1, 2)) {
+                    let c = *rng.choose(&OPEN);
+                    stack.push(c);
+                    line.push(c);
+                } else {
+                    line.push(pair(&stack.pop().unwrap()));
+                }
+            }
+            if stack.is_empty() {
+                let c = *rng.choose(&OPEN);
+                stack.push(c);
+                line.push(c);
+            }
+            if i >= incomplete_lines {
+                // close a chunk with the wrong character
+                let open = stack[stack.len() - 1];
+                let wrong: Vec<char> = OPEN.iter().filter(|&&c| c != open).map(pair).collect();
+                line.push(*rng.choose(&wrong));
+            }
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+pub fn part01(lines: &[&str]) -> i64 {
+    lines
+        .iter()
+        .map(|l| process_line(l))
+        // drop incomplete lines
+        .filter_map(|line_type| match line_type {
+            LineType::Invalid(c) => Some(c),
+            _ => None,
+        })
+        // calculate score
+        .map(|c| match c {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => unreachable!(),
+        })
+        .sum()
+}
+
+pub fn part02(lines: &[&str]) -> anyhow::Result<i64> {
+    let mut scores: Vec<i64> = lines
+        .iter()
+        .map(|l| process_line(l))
+        // drop invalid lines
+        .filter_map(|line_type| match line_type {
+            LineType::Incomplete(ending) => Some(ending),
+            _ => None,
+        })
+        // calculate score
+        .map(|ending_chars| {
+            ending_chars
+                .iter()
+                .try_fold(0i64, |acc, c| {
+                    let points = match c {
+                        ')' => 1,
+                        ']' => 2,
+                        '}' => 3,
+                        '>' => 4,
+                        _ => unreachable!(),
+                    };
+                    acc.checked_mul(5)?.checked_add(points)
+                })
+                .context("completion score does not fit an i64")
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    anyhow::ensure!(
+        scores.len() % 2 == 1,
+        "{} incomplete lines, expected an odd number to have a middle score",
+        scores.len()
+    );
+    scores.sort_unstable();
+    Ok(scores[scores.len() / 2])
+}
+
+#[derive(PartialEq)]
+enum LineType {
+    Invalid(char),
+    Incomplete(Vec<char>),
+}
+
+fn process_line(line: &str) -> LineType {
+    let mut stack = Vec::new();
+    for c in line.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            closing => {
+                // a closing character without any open chunk is corrupted as well
+                if stack.pop().map(|open| pair(&open)) != Some(closing) {
+                    return LineType::Invalid(c);
+                }
+            }
+        }
+    }
+
+    let ending = stack.iter().rev().map(pair).collect();
+    LineType::Incomplete(ending)
+}
+
+fn pair(c: &char) -> char {
+    match c {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => unreachable!(),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    for line in input.lines() {
+        if let Some(i) = line.find(|c| !"()[]{}<>".contains(c)) {
+            let c = line[i..].chars().next().unwrap();
+            let token = &line[i..i + c.len_utf8()];
+            return Err(ParseError::new(DAY, input, token, "one of ()[]{}<>"));
+        }
+    }
+    Ok(input.lines().collect())
+}
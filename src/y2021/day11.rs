@@ -0,0 +1,199 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    grid::{Grid, Point},
+    parse::{self, ParseError},
+    visualize::{Cell, Color, Frame, FrameSink, Visualize},
+};
+
+const DAY: usize = 11;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input<'a> = Grid<u32>;
+
+    fn name(&self) -> &'static str {
+        "Dumbo Octopus"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+// steps until every octopus flashes at once, or until the grid repeats if it never does, the ones
+// that flashed are highlighted
+impl Visualize for Day11 {
+    fn visualize(&self, grid: &Grid<u32>, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let mut repeats = Repeats::new(grid);
+        let mut grid = grid.clone();
+        let mut total = 0;
+        for step_number in 1.. {
+            let flashed = step(&mut grid);
+            total += flashed;
+            let synced = flashed == grid.len() as i64;
+            let repeated = !synced && repeats.check(&grid);
+
+            let cells = grid.map(|&energy| match energy {
+                0 => Cell::colored('0', Color::Yellow, '*'),
+                e => Cell::new(char::from_digit(e, 10).unwrap_or('?')),
+            });
+            let mut caption = format!(
+                "step {}: {} flashed, {} in total",
+                step_number, flashed, total
+            );
+            if repeated {
+                caption.push_str(", repeating without ever all flashing");
+            }
+            sink.frame(Frame::new(caption, cells))?;
+
+            if synced || repeated {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+// most random grids never synchronize, so each grid is simulated and the energy levels are drawn
+// from a narrower range until one does. Levels at most 1 apart always flash together.
+impl Generate for Day11 {
+    fn size_of(&self) -> &'static str {
+        "rows and columns"
+    }
+
+    fn default_size(&self) -> usize {
+        10
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        for spread in (0..=9).rev() {
+            let lo = rng.range(0, 9 - spread) as u32;
+            let input = gen::digit_grid(rng, size, size, lo, lo + spread as u32);
+            if part02(&parse_input(&input).unwrap()).is_ok() {
+                return input;
+            }
+        }
+        unreachable!("octopuses of the same energy level flash together")
+    }
+}
+
+pub fn part01(grid: &Grid<u32>) -> i64 {
+    let mut grid = grid.clone();
+
+    (0..100).map(|_| step(&mut grid)).sum::<i64>()
+}
+
+pub fn part02(grid: &Grid<u32>) -> anyhow::Result<i64> {
+    // energy levels stay between 0 and 9 so the grid eventually repeats itself, if it does before
+    // all of them flash they never will
+    let mut repeats = Repeats::new(grid);
+    let mut grid = grid.clone();
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        if step(&mut grid) == grid.len() as i64 {
+            return Ok(steps);
+        }
+        anyhow::ensure!(
+            !repeats.check(&grid),
+            "the octopuses repeat after {} steps without all flashing together",
+            steps
+        );
+    }
+}
+
+// Brent's cycle detection, each grid is compared against a saved one that is replaced after twice
+// as many steps each time
+struct Repeats {
+    saved: Grid<u32>,
+    since_saved: usize,
+    power: usize,
+}
+
+impl Repeats {
+    fn new(grid: &Grid<u32>) -> Self {
+        Self {
+            saved: grid.clone(),
+            since_saved: 0,
+            power: 1,
+        }
+    }
+
+    // whether `grid`, the grid after the next step, has been seen before
+    fn check(&mut self, grid: &Grid<u32>) -> bool {
+        if *grid == self.saved {
+            return true;
+        }
+        self.since_saved += 1;
+        if self.since_saved == self.power {
+            self.saved = grid.clone();
+            self.since_saved = 0;
+            self.power *= 2;
+        }
+        false
+    }
+}
+
+// runs a single step of the problem returning the number of squid that flashed that step
+fn step(grid: &mut Grid<u32>) -> i64 {
+    // increment energy of each squid, the ones that reach 10 flash
+    grid.values_mut().for_each(|e| *e += 1);
+    let mut flashes: VecDeque<Point> = grid
+        .iter()
+        .filter(|(_, &e)| e > 9)
+        .map(|(p, _)| p)
+        .collect();
+
+    // while we have squids that have flashed, increment the energy of the squids around them, a
+    // squid can only flash once per step
+    let mut flashed = HashSet::new();
+    while let Some(point) = flashes.pop_front() {
+        if !flashed.insert(point) {
+            continue;
+        }
+
+        for neighbor in grid.neighbors8(point).collect::<Vec<_>>() {
+            grid[neighbor] += 1;
+            if grid[neighbor] > 9 {
+                flashes.push_back(neighbor);
+            }
+        }
+    }
+
+    // end of this step, reset energy levels for flashed squids
+    flashed.iter().for_each(|&point| grid[point] = 0);
+
+    flashed.len() as i64
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    parse::digit_grid(DAY, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day11() {
+        let input = include_str!("../../inputs/2021/11.txt");
+        let grid = Day11.parse(input).unwrap();
+
+        assert_eq!(1721, part01(&grid));
+        assert_eq!(298, part02(&grid).unwrap());
+    }
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashMap, fmt::Write};
+
+use anyhow::Context;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 12;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+    fn name(&self) -> &'static str {
+        "Passage Pathing"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part01(input).map(Answer::from)
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(input).map(Answer::from)
+    }
+}
+
+// a tree of caves with a few extra passages, big caves are never connected to each other as
+// there would be infinitely many paths otherwise
+impl Generate for Day12 {
+    fn size_of(&self) -> &'static str {
+        "caves"
+    }
+
+    fn default_size(&self) -> usize {
+        12
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut names = vec!["start".to_owned(), "end".to_owned()];
+        while names.len() < size.max(1) + 2 {
+            let big = rng.chance(1, 4);
+            let name: String = (0..2)
+                .map(|_| {
+                    let c = (b'a' + rng.below(26) as u8) as char;
+                    if big {
+                        c.to_ascii_uppercase()
+                    } else {
+                        c
+                    }
+                })
+                .collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let is_big = |i: usize| names[i].chars().all(|c| c.is_ascii_uppercase());
+
+        let mut edges = Vec::new();
+        let connect = |a: usize, b: usize, edges: &mut Vec<(usize, usize)>| {
+            let new = !edges.contains(&(a, b)) && !edges.contains(&(b, a));
+            if a != b && !(is_big(a) && is_big(b)) && new {
+                edges.push((a, b));
+            }
+        };
+        // the caves after start hang off an earlier cave, end included
+        let mut order: Vec<usize> = (1..names.len()).collect();
+        rng.shuffle(&mut order);
+        order.insert(0, 0);
+        for i in 1..order.len() {
+            let mut parent = order[rng.index(i)];
+            // a big cave can't hang off another big cave, start is always small
+            while is_big(parent) && is_big(order[i]) {
+                parent = order[rng.index(i)];
+            }
+            connect(parent, order[i], &mut edges);
+        }
+        for _ in 0..names.len() / 4 {
+            let (a, b) = (rng.index(names.len()), rng.index(names.len()));
+            connect(a, b, &mut edges);
+        }
+
+        let mut out = String::new();
+        for (a, b) in edges {
+            writeln!(out, "{}-{}", names[a], names[b]).unwrap();
+        }
+        out
+    }
+}
+
+pub fn part01(edges: &HashMap<&str, Vec<&str>>) -> anyhow::Result<i64> {
+    Caves::new(edges).count_paths(false)
+}
+
+pub fn part02(edges: &HashMap<&str, Vec<&str>>) -> anyhow::Result<i64> {
+    Caves::new(edges).count_paths(true)
+}
+
+// paths are counted from small cave to small cave, a big cave in between only adds to the number of
+// ways to get from one to the next as big caves never connect to each other. The paths from a cave
+// only depend on the small caves visited so far and whether one was visited twice yet, so they are
+// remembered by a mask of the visited caves when there are few enough small caves for one.
+struct Caves {
+    // the small caves reachable from each small cave along with how many ways lead there
+    ways: Vec<Vec<(usize, i64)>>,
+    start: usize,
+    end: Option<usize>,
+}
+
+type Memo = HashMap<(usize, u64, bool), Option<i64>>;
+
+impl Caves {
+    fn new(edges: &HashMap<&str, Vec<&str>>) -> Self {
+        let small: Vec<&str> = edges
+            .keys()
+            .copied()
+            .filter(|cave| is_small_cave(cave))
+            .collect();
+        let index: HashMap<&str, usize> = small.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+
+        let ways = small
+            .iter()
+            .map(|cave| {
+                let mut ways: HashMap<usize, i64> = HashMap::new();
+                for &neighbor in &edges[cave] {
+                    if is_small_cave(neighbor) {
+                        *ways.entry(index[neighbor]).or_default() += 1;
+                    } else {
+                        for &through in &edges[neighbor] {
+                            *ways.entry(index[through]).or_default() += 1;
+                        }
+                    }
+                }
+                let mut ways: Vec<(usize, i64)> = ways.into_iter().collect();
+                ways.sort_unstable();
+                ways
+            })
+            .collect();
+        Self {
+            ways,
+            start: index["start"],
+            end: index.get("end").copied(),
+        }
+    }
+
+    fn count_paths(&self, revisit: bool) -> anyhow::Result<i64> {
+        let Some(end) = self.end else {
+            return Ok(0);
+        };
+        let mut visited = vec![false; self.ways.len()];
+        visited[self.start] = true;
+        // without a mask every path is walked, which is only feasible for sparse cave systems
+        let mut memo = (self.ways.len() <= u64::BITS as usize).then(Memo::new);
+        self.paths(&mut memo, end, self.start, &mut visited, !revisit)
+            .context("the number of paths does not fit an i64")
+    }
+
+    // paths from `cave` to `end`, or `None` if there are too many to count
+    fn paths(
+        &self,
+        memo: &mut Option<Memo>,
+        end: usize,
+        cave: usize,
+        visited: &mut [bool],
+        revisited: bool,
+    ) -> Option<i64> {
+        if cave == end {
+            return Some(1);
+        }
+        let key = memo.is_some().then(|| {
+            let mask = visited
+                .iter()
+                .rev()
+                .fold(0u64, |mask, &v| mask << 1 | u64::from(v));
+            (cave, mask, revisited)
+        });
+        if let Some(&paths) = key.and_then(|key| memo.as_ref()?.get(&key)) {
+            return paths;
+        }
+
+        let mut count = Some(0i64);
+        for &(next, ways) in &self.ways[cave] {
+            let seen = visited[next];
+            if next == self.start || (seen && revisited) {
+                continue;
+            }
+            visited[next] = true;
+            let paths = self.paths(memo, end, next, visited, revisited || seen);
+            visited[next] = seen;
+            count = paths
+                .and_then(|paths| paths.checked_mul(ways))
+                .and_then(|paths| count?.checked_add(paths));
+            if count.is_none() {
+                break;
+            }
+        }
+        if let (Some(memo), Some(key)) = (memo, key) {
+            memo.insert(key, count);
+        }
+        count
+    }
+}
+
+fn is_small_cave(cave: &str) -> bool {
+    cave.chars().next().unwrap().is_lowercase()
+}
+
+pub fn parse_input(input: &str) -> Result<HashMap<&str, Vec<&str>>, ParseError> {
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    for line in input.lines() {
+        let (left, right) = parse::split_once(DAY, input, line, "-")?;
+        for cave in [left, right] {
+            if cave.is_empty() || !cave.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::new(DAY, input, cave, "cave name"));
+            }
+        }
+        // paths could go back and forth between two big caves forever
+        if !is_small_cave(left) && !is_small_cave(right) {
+            return Err(ParseError::new(
+                DAY,
+                input,
+                line,
+                "passage to at least one small cave",
+            ));
+        }
+        edges.entry(left).or_default().push(right);
+        edges.entry(right).or_default().push(left);
+    }
+
+    if !edges.contains_key("start") {
+        let end = parse::end_of(input);
+        return Err(ParseError::new(DAY, input, end, "a path from `start`"));
+    }
+    Ok(edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day12() {
+        let input = include_str!("../../inputs/2021/12.txt");
+        let edges = parse_input(input).unwrap();
+
+        assert_eq!(3738, part01(&edges).unwrap());
+        assert_eq!(120506, part02(&edges).unwrap());
+    }
+
+    #[test]
+    fn test_dense_caves() {
+        let mut names = vec!["start".to_owned(), "end".to_owned()];
+        names.extend(('a'..='k').map(|c| format!("{}x", c)));
+        let mut input = String::new();
+        for (i, a) in names.iter().enumerate() {
+            for b in &names[i + 1..] {
+                writeln!(input, "{}-{}", a, b).unwrap();
+            }
+        }
+        let edges = parse_input(&input).unwrap();
+        assert!(part01(&edges).unwrap() < part02(&edges).unwrap());
+    }
+
+    #[test]
+    fn test_many_small_caves() {
+        // too many caves for a mask, a long corridor with a side cave at every step
+        let name = |prefix: char, i: usize| {
+            let digits = [i / 26 % 26, i % 26].map(|d| (b'a' + d as u8) as char);
+            format!("{}{}{}", prefix, digits[0], digits[1])
+        };
+        let mut input = format!("start-{}\n", name('c', 0));
+        for i in 0..100 {
+            writeln!(input, "{}-{}", name('c', i), name('c', i + 1)).unwrap();
+            writeln!(input, "{}-{}", name('c', i), name('s', i)).unwrap();
+        }
+        writeln!(input, "{}-end", name('c', 100)).unwrap();
+        let edges = parse_input(&input).unwrap();
+        assert_eq!(1, part01(&edges).unwrap());
+        // a side trip returns through its corridor cave, which can only happen once
+        assert_eq!(1 + 100, part02(&edges).unwrap());
+    }
+}
@@ -0,0 +1,291 @@
+use std::{cmp::Ordering, collections::HashSet, fmt::Write};
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    grid::Grid,
+    ocr,
+    parse::{self, ParseError},
+    visualize::{Cell, Color, Frame, FrameSink, Visualize},
+};
+
+const DAY: usize = 13;
+
+pub type Point = (usize, usize);
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input<'a> = (Vec<(usize, usize)>, Vec<Fold>);
+
+    fn name(&self) -> &'static str {
+        "Transparent Origami"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(&input.0, &input.1).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(&input.0, &input.1)
+    }
+}
+
+// shows the paper before each fold along with the line it is folded on, then the folded paper
+impl Visualize for Day13 {
+    fn visualize(&self, input: &Self::Input<'_>, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let (points, folds) = input;
+        let (mut width, mut height) = paper_size(points);
+        for i in 0..=folds.len() {
+            // a fold line past the edge makes the paper larger than its dots
+            anyhow::ensure!(
+                width.saturating_mul(height) <= MAX_VISUALIZE,
+                "the paper is {}x{}, too large to visualize",
+                width,
+                height
+            );
+            let mut cells = Grid::new(width, height, Cell::new('.'));
+            for point in execute_folds(points, &folds[..i]) {
+                if let Some(cell) = cells.get_mut(point) {
+                    *cell = Cell::new('#');
+                }
+            }
+
+            let caption = match folds.get(i) {
+                Some(Fold::X(idx)) => {
+                    for y in 0..height {
+                        if let Some(cell) = cells.get_mut((*idx, y)) {
+                            *cell = Cell::colored('|', Color::Yellow, '|');
+                        }
+                    }
+                    width = *idx;
+                    format!("fold {}/{} along x={}", i + 1, folds.len(), idx)
+                }
+                Some(Fold::Y(idx)) => {
+                    for x in 0..width {
+                        if let Some(cell) = cells.get_mut((x, *idx)) {
+                            *cell = Cell::colored('-', Color::Yellow, '-');
+                        }
+                    }
+                    height = *idx;
+                    format!("fold {}/{} along y={}", i + 1, folds.len(), idx)
+                }
+                None => "folded".to_owned(),
+            };
+            sink.frame(Frame::new(caption, cells))?;
+        }
+        Ok(())
+    }
+}
+
+// unfolds random dots in the final 40x6 area back out through the folds, so that the folded
+// paper shows noise rather than letters, no dot is ever on a fold line
+impl Generate for Day13 {
+    fn size_of(&self) -> &'static str {
+        "dots"
+    }
+
+    fn default_size(&self) -> usize {
+        800
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // folding in half along the middle line leaves (size - 1) / 2
+        let (mut width, mut height) = (40, 6);
+        let mut folds = Vec::new();
+        for i in 0..9 {
+            if i % 2 == 0 || i > 6 {
+                folds.push(Fold::X(width));
+                width = width * 2 + 1;
+            } else {
+                folds.push(Fold::Y(height));
+                height = height * 2 + 1;
+            }
+        }
+        folds.reverse();
+
+        let mut out = String::new();
+        for _ in 0..size.max(1) {
+            let (mut x, mut y) = (rng.index(40), rng.index(6));
+            for fold in folds.iter().rev() {
+                match *fold {
+                    Fold::X(idx) if rng.chance(1, 2) => x = 2 * idx - x,
+                    Fold::Y(idx) if rng.chance(1, 2) => y = 2 * idx - y,
+                    _ => {}
+                }
+            }
+            writeln!(out, "{},{}", x, y).unwrap();
+        }
+        out.push('\n');
+        for fold in folds {
+            match fold {
+                Fold::X(idx) => writeln!(out, "fold along x={}", idx),
+                Fold::Y(idx) => writeln!(out, "fold along y={}", idx),
+            }
+            .unwrap();
+        }
+        out
+    }
+}
+
+pub fn part01(points: &[(usize, usize)], folds: &[Fold]) -> i64 {
+    execute_folds(points, &folds[0..1]).len() as i64
+}
+
+// the folded paper shows letters, read them if possible otherwise fall back to the picture
+pub fn part02(points: &[(usize, usize)], folds: &[Fold]) -> anyhow::Result<Answer> {
+    let grid = execute_folds(points, folds);
+    let (width, height) = paper_size(&grid);
+    anyhow::ensure!(
+        width.saturating_mul(height) <= MAX_RENDER,
+        "the folded paper is {}x{}, too large to render",
+        width,
+        height
+    );
+
+    let render = render_grid(&grid);
+    Ok(match ocr::read_letters(&render) {
+        Some(letters) => Answer::Text(letters),
+        None => Answer::Render(render),
+    })
+}
+
+// the most dots rendered as the answer of part 2, real papers fold down to 40x6
+const MAX_RENDER: usize = 1 << 16;
+// the most cells of a visualized paper, real papers start out at about 1300x900
+const MAX_VISUALIZE: usize = 1 << 22;
+
+// the smallest paper that holds every dot
+fn paper_size<'a>(points: impl IntoIterator<Item = &'a Point>) -> (usize, usize) {
+    points.into_iter().fold((0, 0), |(w, h), (x, y)| {
+        (w.max(x.saturating_add(1)), h.max(y.saturating_add(1)))
+    })
+}
+
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+fn execute_folds(points: &[(usize, usize)], folds: &[Fold]) -> HashSet<(usize, usize)> {
+    let mut grid: HashSet<(usize, usize)> = points.iter().copied().collect();
+    for fold in folds {
+        // dots past the fold are mirrored onto the other side, dots on the fold line or mirrored
+        // past the edge of the paper are lost
+        let mirror = |n: usize, idx: usize| match n.cmp(&idx) {
+            Ordering::Less => Some(n),
+            Ordering::Equal => None,
+            Ordering::Greater => idx.checked_sub(n - idx),
+        };
+        grid = grid
+            .into_iter()
+            .filter_map(|(x, y)| match *fold {
+                Fold::X(idx) => Some((mirror(x, idx)?, y)),
+                Fold::Y(idx) => Some((x, mirror(y, idx)?)),
+            })
+            .collect();
+    }
+    grid
+}
+
+fn render_grid(grid: &HashSet<(usize, usize)>) -> String {
+    let (max_x, max_y) = grid.iter().fold((0, 0), |(max_x, max_y), (x, y)| {
+        (max_x.max(*x), max_y.max(*y))
+    });
+
+    let mut render = String::new();
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            render.push(if grid.contains(&(x, y)) { '#' } else { '.' });
+        }
+        render.push('\n');
+    }
+    render
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Point>, Vec<Fold>), ParseError> {
+    let (points, folds) = parse::two_sections(DAY, input, input)?;
+
+    let mut parsed_points = Vec::new();
+    for line in points.lines() {
+        let (x, y) = parse::split_once(DAY, input, line, ",")?;
+        let x = parse::number(DAY, input, x, "x coordinate")?;
+        let y = parse::number(DAY, input, y, "y coordinate")?;
+        parsed_points.push((x, y));
+    }
+
+    let mut parsed_folds = Vec::new();
+    for line in folds.lines() {
+        let s = line
+            .strip_prefix("fold along ")
+            .ok_or_else(|| ParseError::new(DAY, input, line, "`fold along `"))?;
+        let (axis, idx) = parse::split_once(DAY, input, s, "=")?;
+        let idx = parse::number(DAY, input, idx, "fold line")?;
+        let fold = match axis {
+            "x" => Fold::X(idx),
+            "y" => Fold::Y(idx),
+            _ => return Err(ParseError::new(DAY, input, axis, "`x` or `y`")),
+        };
+        parsed_folds.push(fold);
+    }
+
+    if parsed_folds.is_empty() {
+        let end = parse::end_of(input);
+        return Err(ParseError::new(DAY, input, end, "at least one fold"));
+    }
+    Ok((parsed_points, parsed_folds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Frames(usize);
+
+    impl FrameSink for Frames {
+        fn frame(&mut self, _: Frame) -> anyhow::Result<()> {
+            self.0 += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_visualize_too_large() {
+        let mut frames = Frames(0);
+        let input = parse_input("0,0\n1,1\n\nfold along x=2000000000\nfold along y=1\n").unwrap();
+        assert!(Day13.visualize(&input, &mut frames).is_err());
+        assert_eq!(1, frames.0);
+
+        let input = parse_input("1000000000,1\n\nfold along y=1\n").unwrap();
+        assert!(Day13.visualize(&input, &mut frames).is_err());
+    }
+
+    #[test]
+    fn test_day13_example() {
+        let input = include_str!("../../inputs/2021/13.test.txt");
+        let (points, folds) = parse_input(input).unwrap();
+
+        assert_eq!(17, part01(&points, &folds));
+        assert_eq!(
+            Answer::Render("#####\n#...#\n#...#\n#...#\n#####\n".to_owned()),
+            part02(&points, &folds).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_day13() {
+        let input = include_str!("../../inputs/2021/13.txt");
+        let (points, folds) = parse_input(input).unwrap();
+
+        assert_eq!(610, part01(&points, &folds));
+        assert_eq!(
+            Answer::Text("PZFJHRFZ".to_owned()),
+            part02(&points, &folds).unwrap()
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::{collections::HashMap, fmt::Write};
+
+use anyhow::Context;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 14;
+
+// pair insertion rules, a pair of elements maps to the element inserted between them
+pub type Rules = HashMap<(char, char), char>;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input<'a> = (Vec<char>, Rules);
+
+    fn name(&self) -> &'static str {
+        "Extended Polymerization"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part01(&input.0, &input.1).map(Answer::from)
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(&input.0, &input.1).map(Answer::from)
+    }
+}
+
+// a rule for every pair of elements so that no pair is left without an insertion
+impl Generate for Day14 {
+    fn size_of(&self) -> &'static str {
+        "elements in the template"
+    }
+
+    fn default_size(&self) -> usize {
+        20
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        const ELEMENTS: [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+        let mut out: String = (0..size.max(2)).map(|_| *rng.choose(&ELEMENTS)).collect();
+        out.push_str("\n\n");
+        for a in ELEMENTS {
+            for b in ELEMENTS {
+                writeln!(out, "{}{} -> {}", a, b, rng.choose(&ELEMENTS)).unwrap();
+            }
+        }
+        out
+    }
+}
+
+pub fn part01(template: &[char], rules: &Rules) -> anyhow::Result<i64> {
+    solve(10, template, rules)
+}
+
+pub fn part02(template: &[char], rules: &Rules) -> anyhow::Result<i64> {
+    solve(40, template, rules)
+}
+
+fn solve(steps: usize, template: &[char], rules: &Rules) -> anyhow::Result<i64> {
+    // aggregate all pairs into a single map entry with count as a value; there is no need to
+    // iterate all pairs via a string since all input pairs of the same type produce the same two
+    // output pairs. The counts double with every step, so a long template outgrows an i64 well
+    // before it outgrows a u128.
+    let mut pairs: HashMap<(char, char), u128> = HashMap::new();
+
+    // seed with initial template
+    template.windows(2).for_each(|pair| {
+        *pairs.entry((pair[0], pair[1])).or_default() += 1;
+    });
+
+    (1..=steps).for_each(|_| {
+        let mut step_pairs: HashMap<(char, char), u128> = HashMap::new();
+        for (pair, count) in pairs.iter() {
+            // a pair (A, B) produces two new pairs (A, x) and (x, B) where x is the resulting char
+            // in the insertion rules for the pair (A, B)
+            // pairs without a rule are left as they are
+            match rules.get(pair) {
+                Some(c) => {
+                    *step_pairs.entry((pair.0, *c)).or_default() += count;
+                    *step_pairs.entry((*c, pair.1)).or_default() += count;
+                }
+                None => *step_pairs.entry(*pair).or_default() += count,
+            }
+        }
+
+        pairs = step_pairs;
+    });
+
+    // count of individual characters
+    let mut counts: HashMap<char, u128> = HashMap::new();
+    for ((_, r), count) in pairs.iter() {
+        // only count the right char since each char is in two different pairs
+        *counts.entry(*r).or_default() += count;
+    }
+    // add the head of the initial template to the count. this isn't included since we only counted
+    // thi right side of each pair. the head of the template will remain stable throughout the
+    // mutation
+    *counts.entry(*template.iter().next().unwrap()).or_default() += 1;
+
+    // solution is count of most common element - count of least common element
+    let min = counts.values().min().unwrap();
+    let max = counts.values().max().unwrap();
+    i64::try_from(max - min).context("the difference in element counts does not fit an i64")
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<char>, Rules), ParseError> {
+    let (template, rules) = parse::two_sections(DAY, input, input)?;
+    if template.is_empty() || !template.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(ParseError::new(DAY, input, template, "polymer template"));
+    }
+    let template = template.chars().collect();
+
+    let mut parsed_rules = HashMap::new();
+    for line in rules.lines() {
+        let (l, r) = parse::rule(DAY, input, line)?;
+        let pair: Vec<char> = l.chars().collect();
+        if pair.len() != 2 || !pair.iter().all(|c| c.is_ascii_uppercase()) {
+            return Err(ParseError::new(DAY, input, l, "pair of elements"));
+        }
+        let mut rcs = r.chars();
+        let element = match (rcs.next(), rcs.next()) {
+            (Some(c), None) if c.is_ascii_uppercase() => c,
+            _ => return Err(ParseError::new(DAY, input, r, "single element")),
+        };
+        parsed_rules.insert((pair[0], pair[1]), element);
+    }
+
+    Ok((template, parsed_rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const ELEMENTS: [char; 4] = ['A', 'B', 'C', 'D'];
+
+    // builds the whole polymer, only feasible for a few steps
+    fn brute_force(steps: usize, template: &[char], rules: &Rules) -> i64 {
+        let mut polymer = template.to_vec();
+        for _ in 0..steps {
+            let mut next = vec![polymer[0]];
+            for pair in polymer.windows(2) {
+                next.extend([rules[&(pair[0], pair[1])], pair[1]]);
+            }
+            polymer = next;
+        }
+
+        let mut counts: HashMap<char, i64> = HashMap::new();
+        for c in polymer {
+            *counts.entry(c).or_default() += 1;
+        }
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    }
+
+    fn rules() -> impl Strategy<Value = Rules> {
+        prop::collection::vec(prop::sample::select(&ELEMENTS[..]), 16).prop_map(|inserted| {
+            let pairs = ELEMENTS
+                .iter()
+                .flat_map(|&a| ELEMENTS.iter().map(move |&b| (a, b)));
+            pairs.zip(inserted).collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_solve_matches_brute_force(
+            template in prop::collection::vec(prop::sample::select(&ELEMENTS[..]), 1..8),
+            rules in rules(),
+            steps in 0..10usize,
+        ) {
+            prop_assert_eq!(
+                brute_force(steps, &template, &rules),
+                solve(steps, &template, &rules).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_day14_example() {
+        let input = include_str!("../../inputs/2021/14.test.txt");
+        let (template, rules) = parse_input(input).unwrap();
+
+        assert_eq!(1588, part01(&template, &rules).unwrap());
+        assert_eq!(2188189693529, part02(&template, &rules).unwrap());
+    }
+
+    #[test]
+    fn test_counts_past_i64() {
+        // the A pairs double every step while the single B stays, as in a long template after 40
+        let template = ['A', 'A', 'B'];
+        let rules = Rules::from([(('A', 'A'), 'A'), (('A', 'B'), 'A')]);
+
+        assert_eq!(i64::MAX, solve(62, &template, &rules).unwrap());
+        assert!(solve(63, &template, &rules).is_err());
+        assert!(solve(100, &template, &rules).is_err());
+    }
+
+    #[test]
+    fn test_day14() {
+        let input = include_str!("../../inputs/2021/14.txt");
+        let (template, rules) = parse_input(input).unwrap();
+
+        assert_eq!(3247, part01(&template, &rules).unwrap());
+        assert_eq!(4110568157153, part02(&template, &rules).unwrap());
+    }
+}
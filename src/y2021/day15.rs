@@ -0,0 +1,137 @@
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{self, Generate, Rng},
+    grid::{Grid, Point},
+    parse::{self, ParseError},
+    search,
+    visualize::{Cell, Color, Frame, FrameSink, Visualize},
+};
+
+const DAY: usize = 15;
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input<'a> = Grid<u32>;
+
+    fn name(&self) -> &'static str {
+        "Chiton"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part02(input).into())
+    }
+}
+
+// follows the lowest risk path of part 1 one position at a time
+impl Visualize for Day15 {
+    fn visualize(&self, grid: &Grid<u32>, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let (total_risk, path) = lowest_risk_path(grid);
+        let mut cells = grid.map(|risk| Cell::new(char::from_digit(*risk, 10).unwrap_or('?')));
+
+        let mut risk = 0;
+        for (i, &point) in path.iter().enumerate() {
+            // the risk of the starting position isn't counted
+            if i > 0 {
+                risk += grid[point];
+            }
+            cells[point] = Cell::colored(cells[point].ch, Color::Red, '*');
+            let caption = format!(
+                "step {}/{}, risk {}/{}",
+                i,
+                path.len() - 1,
+                risk,
+                total_risk
+            );
+            sink.frame(Frame::new(caption, cells.clone()))?;
+        }
+        Ok(())
+    }
+}
+
+impl Generate for Day15 {
+    fn size_of(&self) -> &'static str {
+        "rows and columns"
+    }
+
+    fn default_size(&self) -> usize {
+        100
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        gen::digit_grid(rng, size, size, 1, 9)
+    }
+}
+
+pub fn part01(grid: &Grid<u32>) -> i64 {
+    let (total_risk, _) = lowest_risk_path(grid);
+    total_risk
+}
+
+pub fn part02(grid: &Grid<u32>) -> i64 {
+    let (total_risk, _) = lowest_risk_path(&extend(grid));
+    total_risk
+}
+
+// converts single grid into extended grid that is 5 times the size
+pub fn extend(grid: &Grid<u32>) -> Grid<u32> {
+    let (size_x, size_y) = (grid.width(), grid.height());
+
+    // extend the grid to 5 times it's original size
+    let mut extended = Grid::new(size_x * 5, size_y * 5, 0);
+    for (new_x, new_y) in extended.points().collect::<Vec<_>>() {
+        // "big" row and col # along with the point in the initial grid
+        let (row, col) = (new_y / size_y, new_x / size_x);
+        let initial = grid[(new_x % size_x, new_y % size_y)];
+
+        // add 1 for each "big" row or col that you are away from the initial
+        let mut v = initial + (row + col) as u32;
+
+        // handle 9->1 wrapping
+        if v > 9 {
+            v -= 9;
+        }
+
+        extended[(new_x, new_y)] = v;
+    }
+    extended
+}
+
+// returns the total risk of the path with the lowest risk from top-left to bot-right
+pub fn lowest_risk_path(grid: &Grid<u32>) -> (i64, Vec<Point>) {
+    // moving onto a position costs its risk
+    let neighbors = |point| grid.neighbors4(point).map(|n| (n, grid[n] as u64));
+    let destination = (grid.width() - 1, grid.height() - 1);
+
+    let path = search::dijkstra(&neighbors, (0, 0), |&p| p == destination)
+        .expect("the bottom right is always reachable");
+    (path.cost as i64, path.nodes)
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    parse::digit_grid(DAY, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day15_example() {
+        let input = include_str!("../../inputs/2021/15.test.txt");
+        let grid = parse_input(input).unwrap();
+
+        assert_eq!(40, part01(&grid));
+        assert_eq!(315, part02(&grid));
+    }
+}
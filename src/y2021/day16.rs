@@ -0,0 +1,573 @@
+use std::{collections::VecDeque, io::Cursor};
+
+use anyhow::Context;
+use bytes::Buf;
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    parse::ParseError,
+};
+
+const DAY: usize = 16;
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input<'a> = Transmission;
+
+    fn name(&self) -> &'static str {
+        "Packet Decoder"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        parse_input(input).map(Transmission)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(&input.0).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        part02(&input.0).map(Answer::from)
+    }
+}
+
+// nests operators `size` deep, each with a few literal siblings next to the deeper packet. Products
+// only ever hold literals so that the value stays small enough to compute.
+impl Generate for Day16 {
+    fn size_of(&self) -> &'static str {
+        "levels of nested packets"
+    }
+
+    fn default_size(&self) -> usize {
+        50
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut bits = Bits::default();
+        // literal siblings that come after the deeper packet, written once it is done
+        let mut after: Vec<Vec<u64>> = Vec::new();
+        for _ in 0..size {
+            let type_id = *rng.choose(&[0, 2, 3, 5, 6, 7]);
+            let siblings = match type_id {
+                // comparisons have exactly two sub-packets
+                5..=7 => 1,
+                _ => rng.index(3),
+            };
+            let literals: Vec<u64> = (0..siblings).map(|_| rng.below(100)).collect();
+            let split = rng.index(siblings + 1);
+
+            bits.push(rng.below(8), 3);
+            bits.push(type_id, 3);
+            // the length in bits isn't known up front, so always count the sub-packets
+            bits.push(1, 1);
+            bits.push(siblings as u64 + 1, 11);
+            for &n in &literals[..split] {
+                literal_or_product(rng, &mut bits, n);
+            }
+            after.push(literals[split..].to_vec());
+        }
+        let deepest = rng.below(1000);
+        literal_or_product(rng, &mut bits, deepest);
+        while let Some(literals) = after.pop() {
+            for n in literals {
+                literal_or_product(rng, &mut bits, n);
+            }
+        }
+        bits.to_hex()
+    }
+}
+
+// a literal packet worth `n`, or sometimes a product of small literals
+fn literal_or_product(rng: &mut Rng, bits: &mut Bits, n: u64) {
+    let version = rng.below(8);
+    if rng.chance(1, 4) {
+        let factors: Vec<u64> = (0..rng.range(1, 3)).map(|_| rng.below(10)).collect();
+        let mut packets = Bits::default();
+        for factor in factors {
+            packets.push(rng.below(8), 3);
+            packets.push_literal(factor);
+        }
+        bits.push(version, 3);
+        bits.push(1, 3);
+        // the sub-packets are known here, so their length can be given in bits
+        bits.push(0, 1);
+        bits.push(packets.0.len() as u64, 15);
+        bits.0.extend(packets.0);
+    } else {
+        bits.push(version, 3);
+        bits.push_literal(n);
+    }
+}
+
+#[derive(Default)]
+struct Bits(Vec<bool>);
+
+impl Bits {
+    fn push(&mut self, value: u64, width: usize) {
+        self.0
+            .extend((0..width).rev().map(|i| (value >> i) & 1 == 1));
+    }
+
+    // the type id and groups of a literal value, the version is pushed by the caller
+    fn push_literal(&mut self, value: u64) {
+        self.push(4, 3);
+        let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+        for i in (0..groups).rev() {
+            self.push(u64::from(i > 0), 1);
+            self.push((value >> (i * 4)) & 0xf, 4);
+        }
+    }
+
+    fn to_hex(&self) -> String {
+        let mut hex: String = self
+            .0
+            .chunks(4)
+            .map(|nibble| {
+                let n = (0..4).fold(0, |n, i| n << 1 | u32::from(nibble.get(i) == Some(&true)));
+                char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect();
+        hex.push('\n');
+        hex
+    }
+}
+
+pub fn part01(packet: &Packet) -> i64 {
+    let mut version_sum = 0i64;
+
+    let mut q = VecDeque::new();
+    q.push_front(packet);
+
+    while let Some(packet) = q.pop_front() {
+        version_sum += match &packet.packet_type {
+            PacketType::Literal(_) => packet.version as i64,
+            PacketType::Sum(subpackets)
+            | PacketType::Product(subpackets)
+            | PacketType::Minimum(subpackets)
+            | PacketType::Maximum(subpackets)
+            | PacketType::LessThan(subpackets)
+            | PacketType::GreaterThan(subpackets)
+            | PacketType::EqualTo(subpackets) => {
+                for p in subpackets {
+                    q.push_back(p);
+                }
+                packet.version as i64
+            }
+        };
+    }
+
+    version_sum
+}
+
+pub fn part02(packet: &Packet) -> anyhow::Result<i64> {
+    packet
+        .compute()
+        .and_then(|value| i64::try_from(value).ok())
+        .context("the packet value does not fit an i64")
+}
+
+pub fn parse_input(input: &str) -> anyhow::Result<Packet> {
+    let bin = to_binary(input)?;
+    parse(&mut Cursor::new(&bin[..]))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Packet {
+    pub version: u8,
+    pub packet_type: PacketType,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PacketType {
+    Literal(u64),
+    Sum(Vec<Packet>),
+    Product(Vec<Packet>),
+    Minimum(Vec<Packet>),
+    Maximum(Vec<Packet>),
+    GreaterThan(Vec<Packet>),
+    LessThan(Vec<Packet>),
+    EqualTo(Vec<Packet>),
+}
+
+// the packets nest as deep as the transmission is long, so they are parsed and computed with
+// explicit stacks rather than a call per level
+impl Packet {
+    /// Evaluates the expression this packet represents, or `None` if a sum or product overflows.
+    pub fn compute(&self) -> Option<u64> {
+        // packets being evaluated along with the values of their sub-packets so far
+        let mut stack: Vec<(&Packet, Vec<u64>)> = vec![(self, Vec::new())];
+        loop {
+            let (packet, values) = stack.last().expect("the root is popped last");
+            let packet = *packet;
+            if let Some(next) = packet.subpackets().get(values.len()) {
+                stack.push((next, Vec::new()));
+                continue;
+            }
+            let value = packet.apply(values)?;
+            stack.pop();
+            match stack.last_mut() {
+                Some((_, values)) => values.push(value),
+                None => return Some(value),
+            }
+        }
+    }
+
+    // the value of this packet given the values of its sub-packets
+    fn apply(&self, values: &[u64]) -> Option<u64> {
+        match &self.packet_type {
+            PacketType::Literal(n) => Some(*n),
+            PacketType::Sum(_) => values.iter().try_fold(0u64, |a, &b| a.checked_add(b)),
+            PacketType::Product(_) => values.iter().try_fold(1u64, |a, &b| a.checked_mul(b)),
+            PacketType::Minimum(_) => values.iter().copied().min(),
+            PacketType::Maximum(_) => values.iter().copied().max(),
+            PacketType::GreaterThan(_) => Some(u64::from(values[0] > values[1])),
+            PacketType::LessThan(_) => Some(u64::from(values[0] < values[1])),
+            PacketType::EqualTo(_) => Some(u64::from(values[0] == values[1])),
+        }
+    }
+
+    fn subpackets(&self) -> &[Packet] {
+        match &self.packet_type {
+            PacketType::Literal(_) => &[],
+            PacketType::Sum(packets)
+            | PacketType::Product(packets)
+            | PacketType::Minimum(packets)
+            | PacketType::Maximum(packets)
+            | PacketType::GreaterThan(packets)
+            | PacketType::LessThan(packets)
+            | PacketType::EqualTo(packets) => packets,
+        }
+    }
+
+    fn take_subpackets(&mut self) -> Vec<Packet> {
+        match &mut self.packet_type {
+            PacketType::Literal(_) => Vec::new(),
+            PacketType::Sum(packets)
+            | PacketType::Product(packets)
+            | PacketType::Minimum(packets)
+            | PacketType::Maximum(packets)
+            | PacketType::GreaterThan(packets)
+            | PacketType::LessThan(packets)
+            | PacketType::EqualTo(packets) => std::mem::take(packets),
+        }
+    }
+}
+
+/// A parsed packet kept by [`Day16`], dropped a level at a time as the packets can nest far deeper
+/// than the stack allows.
+pub struct Transmission(Packet);
+
+impl Drop for Transmission {
+    fn drop(&mut self) {
+        dismantle(self.0.take_subpackets());
+    }
+}
+
+fn dismantle(mut pending: Vec<Packet>) {
+    while let Some(mut packet) = pending.pop() {
+        pending.append(&mut packet.take_subpackets());
+    }
+}
+
+// an operator packet whose sub-packets are still being parsed, dropped along with them when the
+// transmission turns out to be malformed
+struct Operator {
+    version: u8,
+    type_id: u8,
+    length: Length,
+    subpackets: Vec<Packet>,
+}
+
+enum Length {
+    // position in the stream where the sub-packets end
+    Bits(u64),
+    Count(usize),
+}
+
+impl Drop for Operator {
+    fn drop(&mut self) {
+        dismantle(std::mem::take(&mut self.subpackets));
+    }
+}
+
+impl Operator {
+    fn start(src: &mut Cursor<&[u8]>, version: u8, type_id: u8) -> anyhow::Result<Self> {
+        let length = match get_u8(src, 1)? {
+            0 => {
+                let bits = get_u32(src, 15)? as u64;
+                Length::Bits(src.position() + bits)
+            }
+            _ => Length::Count(get_u32(src, 11)? as usize),
+        };
+        Ok(Self {
+            version,
+            type_id,
+            length,
+            subpackets: Vec::new(),
+        })
+    }
+
+    fn is_complete(&self, position: u64) -> anyhow::Result<bool> {
+        Ok(match self.length {
+            Length::Bits(end) => {
+                anyhow::ensure!(
+                    position <= end,
+                    "sub-packets run {} bits past their length",
+                    position - end
+                );
+                position == end
+            }
+            Length::Count(n) => self.subpackets.len() == n,
+        })
+    }
+
+    fn finish(mut self) -> anyhow::Result<Packet> {
+        let subpackets = std::mem::take(&mut self.subpackets);
+        match self.type_id {
+            2 | 3 => anyhow::ensure!(
+                !subpackets.is_empty(),
+                "minimum and maximum packets need at least one sub-packet"
+            ),
+            5..=7 => anyhow::ensure!(
+                subpackets.len() == 2,
+                "comparison packets need exactly two sub-packets, found {}",
+                subpackets.len()
+            ),
+            _ => {}
+        }
+
+        let packet_type = match self.type_id {
+            0 => PacketType::Sum(subpackets),
+            1 => PacketType::Product(subpackets),
+            2 => PacketType::Minimum(subpackets),
+            3 => PacketType::Maximum(subpackets),
+            5 => PacketType::GreaterThan(subpackets),
+            6 => PacketType::LessThan(subpackets),
+            7 => PacketType::EqualTo(subpackets),
+            _ => unreachable!("type id 4 is a literal"),
+        };
+
+        Ok(Packet {
+            version: self.version,
+            packet_type,
+        })
+    }
+}
+
+fn parse(src: &mut Cursor<&[u8]>) -> anyhow::Result<Packet> {
+    let mut operators: Vec<Operator> = Vec::new();
+    loop {
+        let version = get_u8(src, 3)?;
+        let ptype = get_u8(src, 3)?;
+        let mut done = match ptype {
+            4 => Some(parse_literal(src, version)?),
+            _ => {
+                operators.push(Operator::start(src, version, ptype)?);
+                None
+            }
+        };
+
+        // hand the finished packet to its operator, finishing every operator that is now complete
+        loop {
+            let Some(operator) = operators.last_mut() else {
+                return Ok(done.expect("a packet was read"));
+            };
+            if let Some(packet) = done.take() {
+                operator.subpackets.push(packet);
+            }
+            if !operator.is_complete(src.position())? {
+                break;
+            }
+            let operator = operators.pop().expect("checked above");
+            done = Some(operator.finish()?);
+        }
+    }
+}
+
+fn parse_literal(src: &mut Cursor<&[u8]>, version: u8) -> anyhow::Result<Packet> {
+    let mut n: u64 = 0;
+    loop {
+        let cont = get_u8(src, 1)?;
+        anyhow::ensure!(n >> 60 == 0, "literal value does not fit 64 bits");
+        n <<= 4;
+        n |= get_u8(src, 4)? as u64;
+        if cont == 0 {
+            break;
+        }
+    }
+
+    Ok(Packet {
+        version,
+        packet_type: PacketType::Literal(n),
+    })
+}
+
+fn get_u8(src: &mut Cursor<&[u8]>, nbits: usize) -> anyhow::Result<u8> {
+    Ok(get_u32(src, nbits)? as u8)
+}
+
+fn get_u32(src: &mut Cursor<&[u8]>, nbits: usize) -> anyhow::Result<u32> {
+    let mut n = 0u32;
+    for _ in 0..nbits {
+        anyhow::ensure!(
+            src.has_remaining(),
+            "the transmission ends in the middle of a packet"
+        );
+        n <<= 1;
+        n |= src.get_u8() as u32;
+    }
+    Ok(n)
+}
+
+fn to_binary(hex: &str) -> Result<Vec<u8>, ParseError> {
+    let mut binary: Vec<u8> = Vec::with_capacity(hex.len() * 4);
+    for (i, ch) in hex.char_indices() {
+        let bits: [u8; 4] = match ch {
+            '0' => [0, 0, 0, 0],
+            '1' => [0, 0, 0, 1],
+            '2' => [0, 0, 1, 0],
+            '3' => [0, 0, 1, 1],
+            '4' => [0, 1, 0, 0],
+            '5' => [0, 1, 0, 1],
+            '6' => [0, 1, 1, 0],
+            '7' => [0, 1, 1, 1],
+            '8' => [1, 0, 0, 0],
+            '9' => [1, 0, 0, 1],
+            'A' => [1, 0, 1, 0],
+            'B' => [1, 0, 1, 1],
+            'C' => [1, 1, 0, 0],
+            'D' => [1, 1, 0, 1],
+            'E' => [1, 1, 1, 0],
+            'F' => [1, 1, 1, 1],
+            '\n' => continue,
+            _ => {
+                let token = &hex[i..i + ch.len_utf8()];
+                return Err(ParseError::new(DAY, hex, token, "hexadecimal digit"));
+            }
+        };
+        bits.iter().for_each(|b| binary.push(*b));
+    }
+    Ok(binary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_binary_string() {
+        let binstr = to_binary("D2FE28").unwrap();
+        let expected: Vec<u8> = "110100101111111000101000"
+            .chars()
+            .map(|c| if c == '1' { 1 } else { 0 })
+            .collect();
+        assert_eq!(expected, binstr);
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        let bin = to_binary("D2FE28").unwrap();
+        let expected = Packet {
+            version: 6,
+            packet_type: PacketType::Literal(2021),
+        };
+        let mut c = Cursor::new(&bin[..]);
+        assert_eq!(expected, parse(&mut c).unwrap());
+    }
+
+    #[test]
+    fn test_parse_operator_1() {
+        let bin = to_binary("EE00D40C823060").unwrap();
+        let expected = Packet {
+            version: 7,
+            packet_type: PacketType::Maximum(vec![
+                Packet {
+                    version: 2,
+                    packet_type: PacketType::Literal(1),
+                },
+                Packet {
+                    version: 4,
+                    packet_type: PacketType::Literal(2),
+                },
+                Packet {
+                    version: 1,
+                    packet_type: PacketType::Literal(3),
+                },
+            ]),
+        };
+        let mut c = Cursor::new(&bin[..]);
+        assert_eq!(expected, parse(&mut c).unwrap());
+    }
+
+    #[test]
+    fn part01_tests() {
+        assert_eq!(16, part01(&parse_input("8A004A801A8002F478").unwrap()));
+        assert_eq!(
+            12,
+            part01(&parse_input("620080001611562C8802118E34").unwrap())
+        );
+        assert_eq!(
+            23,
+            part01(&parse_input("C0015000016115A2E0802F182340").unwrap())
+        );
+        assert_eq!(
+            31,
+            part01(&parse_input("A0016C880162017C3686B18A3D4780").unwrap())
+        );
+
+        // puzzle input
+        assert_eq!(897, part01(&parse_input("620D7800996600E43184312CC01A88913E1E180310FA324649CD5B9DA6BFD107003A4FDE9C718593003A5978C00A7003C400A70025400D60259D400B3002880792201B89400E601694804F1201119400C600C144008100340013440021279A5801AE93CA84C10CF3D100875401374F67F6119CA46769D8664E76FC9E4C01597748704011E4D54D7C0179B0A96431003A48ECC015C0068670FA7EF1BC5166CE440239EFC226F228129E8C1D6633596716E7D4840129C4C8CA8017FCFB943699B794210CAC23A612012EB40151006E2D4678A4200EC548CF12E4FDE9BD4A5227C600F80021D08219C1A00043A27C558AA200F4788C91A1002C893AB24F722C129BDF5121FA8011335868F1802AE82537709999796A7176254A72F8E9B9005BD600A4FD372109FA6E42D1725EDDFB64FFBD5B8D1802323DC7E0D1600B4BCDF6649252B0974AE48D4C0159392DE0034B356D626A130E44015BD80213183A93F609A7628537EB87980292A0D800F94B66546896CCA8D440109F80233ABB3ABF3CB84026B5802C00084C168291080010C87B16227CB6E454401946802735CA144BA74CFF71ADDC080282C00546722A1391549318201233003361006A1E419866200DC758330525A0C86009CC6E7F2BA00A4E7EF7AD6E873F7BD6B741300578021B94309ABE374CF7AE7327220154C3C4BD395C7E3EB756A72AC10665C08C010D0046458E72C9B372EAB280372DFE1BCA3ECC1690046513E5D5E79C235498B9002BD132451A5C78401B99AFDFE7C9A770D8A0094EDAC65031C0178AB3D8EEF8E729F2C200D26579BEDF277400A9C8FE43D3030E010C6C9A078853A431C0C0169A5CB00400010F8C9052098002191022143D30047C011100763DC71824200D4368391CA651CC0219C51974892338D0").unwrap()));
+    }
+
+    #[test]
+    fn part02_tests() {
+        assert_eq!(3, part02(&parse_input("C200B40A82").unwrap()).unwrap());
+        assert_eq!(54, part02(&parse_input("04005AC33890").unwrap()).unwrap());
+        assert_eq!(7, part02(&parse_input("880086C3E88112").unwrap()).unwrap());
+        assert_eq!(9, part02(&parse_input("CE00C43D881120").unwrap()).unwrap());
+        assert_eq!(1, part02(&parse_input("D8005AC2A8F0").unwrap()).unwrap());
+        assert_eq!(0, part02(&parse_input("F600BC2D8F").unwrap()).unwrap());
+        assert_eq!(0, part02(&parse_input("9C005AC2F8F0").unwrap()).unwrap());
+        assert_eq!(
+            1,
+            part02(&parse_input("9C0141080250320F1802104A08").unwrap()).unwrap()
+        );
+
+        // puzzle input
+        assert_eq!(9485076995911, part02(&parse_input("620D7800996600E43184312CC01A88913E1E180310FA324649CD5B9DA6BFD107003A4FDE9C718593003A5978C00A7003C400A70025400D60259D400B3002880792201B89400E601694804F1201119400C600C144008100340013440021279A5801AE93CA84C10CF3D100875401374F67F6119CA46769D8664E76FC9E4C01597748704011E4D54D7C0179B0A96431003A48ECC015C0068670FA7EF1BC5166CE440239EFC226F228129E8C1D6633596716E7D4840129C4C8CA8017FCFB943699B794210CAC23A612012EB40151006E2D4678A4200EC548CF12E4FDE9BD4A5227C600F80021D08219C1A00043A27C558AA200F4788C91A1002C893AB24F722C129BDF5121FA8011335868F1802AE82537709999796A7176254A72F8E9B9005BD600A4FD372109FA6E42D1725EDDFB64FFBD5B8D1802323DC7E0D1600B4BCDF6649252B0974AE48D4C0159392DE0034B356D626A130E44015BD80213183A93F609A7628537EB87980292A0D800F94B66546896CCA8D440109F80233ABB3ABF3CB84026B5802C00084C168291080010C87B16227CB6E454401946802735CA144BA74CFF71ADDC080282C00546722A1391549318201233003361006A1E419866200DC758330525A0C86009CC6E7F2BA00A4E7EF7AD6E873F7BD6B741300578021B94309ABE374CF7AE7327220154C3C4BD395C7E3EB756A72AC10665C08C010D0046458E72C9B372EAB280372DFE1BCA3ECC1690046513E5D5E79C235498B9002BD132451A5C78401B99AFDFE7C9A770D8A0094EDAC65031C0178AB3D8EEF8E729F2C200D26579BEDF277400A9C8FE43D3030E010C6C9A078853A431C0C0169A5CB00400010F8C9052098002191022143D30047C011100763DC71824200D4368391CA651CC0219C51974892338D0").unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_deep_packets() {
+        // deep enough to overflow the stack if a packet took a call per level
+        let input = Day16.generate(&mut Rng::new(1), 100_000);
+        let transmission = Day16.parse(&input).unwrap();
+        assert!(part01(&transmission.0) > 0);
+        assert!(part02(&transmission.0).is_ok());
+
+        // cut off in the outermost packets, the deep ones dropped along with the error
+        let input = input.trim_end();
+        assert!(Day16.parse(&input[..input.len() - 4]).is_err());
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(
+            "the transmission ends in the middle of a packet",
+            parse_input("D2FE").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "comparison packets need exactly two sub-packets, found 1",
+            parse_input("D8002D408").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_to_binary_invalid() {
+        let err = to_binary("D2FG28").unwrap_err();
+        assert_eq!((1, 4, "G"), (err.line, err.column, err.found.as_str()));
+    }
+}
@@ -0,0 +1,221 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    challenge::Solver,
+    gen::{Generate, Rng},
+    parse::{self, ParseError},
+};
+
+const DAY: usize = 17;
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input<'a> = Rect;
+
+    fn name(&self) -> &'static str {
+        "Trick Shot"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Self::Input<'a>> {
+        Ok(input.parse()?)
+    }
+
+    fn part01(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part01(input).into())
+    }
+
+    fn part02(&self, input: &Self::Input<'_>) -> anyhow::Result<Answer> {
+        Ok(part02(input).into())
+    }
+}
+
+// a target below and to the right of the launcher that grows with the size. Like the real targets
+// its x range holds a triangular number, where a probe launched with that x velocity stops moving
+// sideways, so that there are shots going up and falling straight down into it.
+impl Generate for Day17 {
+    fn size_of(&self) -> &'static str {
+        "distance to the target"
+    }
+
+    fn default_size(&self) -> usize {
+        100
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // the far edge of the target is 2.5 times the size, which has to stay within MAX_DISTANCE
+        let size = size.clamp(2, MAX_DISTANCE as usize / 5 * 2) as i64;
+        let triangular: Vec<i64> = (1..)
+            .map(|n| n * (n + 1) / 2)
+            .skip_while(|&t| t < size)
+            .take_while(|&t| t <= size * 2)
+            .collect();
+        let stop = *rng.choose(&triangular);
+        let width = rng.range(0, size / 2);
+        let x1 = stop - rng.range(0, width);
+        let x2 = x1 + width;
+        let y1 = -rng.range(size, size * 2);
+        let y2 = y1 + rng.range(0, size / 2);
+        format!("target area: x={}..{}, y={}..{}\n", x1, x2, y1, y2)
+    }
+}
+
+pub fn part01(target_area: &Rect) -> i64 {
+    run_simulations(target_area).into_iter().max().unwrap() as i64
+}
+
+pub fn part02(target_area: &Rect) -> i64 {
+    run_simulations(target_area).iter().len() as i64
+}
+
+fn run_simulations(target_area: &Rect) -> Vec<i32> {
+    let mut results = Vec::new();
+    let ymin = -target_area.br.1.abs();
+    let ymax = target_area.br.1.abs();
+    for xv in 0..=target_area.br.0 {
+        for yv in ymin..ymax {
+            if let Some(ymax) = simulate(target_area, xv, yv) {
+                results.push(ymax);
+            }
+        }
+    }
+    results
+}
+
+fn simulate(target_area: &Rect, mut xv: i32, mut yv: i32) -> Option<i32> {
+    let mut pos = (0, 0);
+    let mut ymax = 0;
+    loop {
+        pos.0 += xv;
+        pos.1 += yv;
+        ymax = ymax.max(pos.1);
+
+        if target_area.contains(pos) {
+            return Some(ymax);
+        }
+
+        // went down past bottom of target area
+        if yv < 0 && pos.1 < target_area.br.1 {
+            return None;
+        }
+
+        // drag
+        match xv.cmp(&0) {
+            Ordering::Greater => xv -= 1,
+            Ordering::Less => xv += 1,
+            Ordering::Equal => (),
+        }
+
+        // gravity
+        yv -= 1;
+    }
+}
+
+const MAX_DISTANCE: i32 = 500;
+
+pub struct Rect {
+    pub tl: (i32, i32),
+    pub br: (i32, i32),
+}
+
+impl Rect {
+    fn contains(&self, pos: (i32, i32)) -> bool {
+        pos.0 >= self.tl.0 && pos.0 <= self.br.0 && pos.1 >= self.br.1 && pos.1 <= self.tl.1
+    }
+}
+
+impl FromStr for Rect {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // strip "target area: "
+        let coords = input
+            .trim()
+            .strip_prefix("target area: ")
+            .ok_or_else(|| ParseError::new(DAY, input, input, "`target area: `"))?;
+
+        // split "150..193, y=-136..-86" into "150..193" and "y=-136..-86"
+        let (xrange, yrange) = parse::split_once(DAY, input, coords, ", ")?;
+
+        // extract numbers from "x=150..193"
+        let (xs, ys) = (xrange, yrange);
+        let xrange = parse::key_range(DAY, input, xs, "x")?;
+        let yrange = parse::key_range(DAY, input, ys, "y")?;
+
+        // the probe is launched towards a target ahead of and below it, which is limited in size
+        // to keep the number of launches to try reasonable
+        if xrange.is_empty() || *xrange.start() < 1 || *xrange.end() > MAX_DISTANCE {
+            let expected = format!("x range within 1..{}", MAX_DISTANCE);
+            return Err(ParseError::new(DAY, input, xs, expected));
+        }
+        if yrange.is_empty() || *yrange.start() < -MAX_DISTANCE || *yrange.end() > -1 {
+            let expected = format!("y range within -{}..-1", MAX_DISTANCE);
+            return Err(ParseError::new(DAY, input, ys, expected));
+        }
+
+        Ok(Rect {
+            tl: (*xrange.start(), *yrange.end()),
+            br: (*xrange.end(), *yrange.start()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_shots_go_up() {
+        for seed in 0..20 {
+            let input = Day17.generate(&mut Rng::new(seed), 100);
+            assert!(part01(&input.parse().unwrap()) > 0, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_part01() {
+        assert_eq!(
+            45,
+            part01(
+                &include_str!("../../inputs/2021/17.test.txt")
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            9180,
+            part01(&include_str!("../../inputs/2021/17.txt").parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_part02() {
+        assert_eq!(
+            112,
+            part02(
+                &include_str!("../../inputs/2021/17.test.txt")
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            3767,
+            part02(&include_str!("../../inputs/2021/17.txt").parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "target area: x=20..30, y=-10..oops"
+            .parse::<Rect>()
+            .err()
+            .unwrap();
+        assert_eq!((1, 31), (err.line, err.column));
+        assert_eq!("oops", err.found);
+
+        assert!("target area".parse::<Rect>().is_err());
+        assert!("target area: x=30..20, y=-10..-5".parse::<Rect>().is_err());
+        assert!("target area: x=20..30, y=5..10".parse::<Rect>().is_err());
+    }
+}
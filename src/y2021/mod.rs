@@ -0,0 +1,67 @@
+//! Advent of Code 2021.
+
+use crate::registry::Registry;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+
+pub const YEAR: usize = 2021;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(YEAR, 1, day01::Day01);
+    registry.register(YEAR, 2, day02::Day02);
+    registry.register(YEAR, 3, day03::Day03);
+    registry.register(YEAR, 4, day04::Day04);
+    registry.register(YEAR, 5, day05::Day05);
+    registry.register(YEAR, 6, day06::Day06);
+    registry.register(YEAR, 7, day07::Day07);
+    registry.register(YEAR, 8, day08::Day08);
+    registry.register(YEAR, 9, day09::Day09);
+    registry.register(YEAR, 10, day10::Day10);
+    registry.register(YEAR, 11, day11::Day11);
+    registry.register(YEAR, 12, day12::Day12);
+    registry.register(YEAR, 13, day13::Day13);
+    registry.register(YEAR, 14, day14::Day14);
+    registry.register(YEAR, 15, day15::Day15);
+    registry.register(YEAR, 16, day16::Day16);
+    registry.register(YEAR, 17, day17::Day17);
+
+    registry.register_visualization(YEAR, 5, day05::Day05);
+    registry.register_visualization(YEAR, 9, day09::Day09);
+    registry.register_visualization(YEAR, 11, day11::Day11);
+    registry.register_visualization(YEAR, 13, day13::Day13);
+    registry.register_visualization(YEAR, 15, day15::Day15);
+
+    registry.register_generator(YEAR, 1, day01::Day01);
+    registry.register_generator(YEAR, 2, day02::Day02);
+    registry.register_generator(YEAR, 3, day03::Day03);
+    registry.register_generator(YEAR, 4, day04::Day04);
+    registry.register_generator(YEAR, 5, day05::Day05);
+    registry.register_generator(YEAR, 6, day06::Day06);
+    registry.register_generator(YEAR, 7, day07::Day07);
+    registry.register_generator(YEAR, 8, day08::Day08);
+    registry.register_generator(YEAR, 9, day09::Day09);
+    registry.register_generator(YEAR, 10, day10::Day10);
+    registry.register_generator(YEAR, 11, day11::Day11);
+    registry.register_generator(YEAR, 12, day12::Day12);
+    registry.register_generator(YEAR, 13, day13::Day13);
+    registry.register_generator(YEAR, 14, day14::Day14);
+    registry.register_generator(YEAR, 15, day15::Day15);
+    registry.register_generator(YEAR, 16, day16::Day16);
+    registry.register_generator(YEAR, 17, day17::Day17);
+}